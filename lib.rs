@@ -8,14 +8,55 @@ stored in a single u8 vector. This covers the following use cases:
 4 - Good Deeds (as beneficiary - date - location - keywords)
 5 - Original IP (as title - keywords - file hash)
 
-IMPORTANT: THE GET_DETAILS MESSAGE RETURNS THE DETAILS OF A GIVEN CLAIM
-HASH, STARTING WITH A CODE NUMBER (1-5) TO TELL YOU WHAT TYPE OF CLAIM IT WAS.
-
-NOTE: This contract offers a uniquely titled copy of the same message 
-and associated event for each use case when making a claim so that we 
-will know how to parse the u8 vector of claim metadata later in the front
-end. Only a single message and associated event type is needed for claim 
-endorsements. 
+IMPORTANT: THE GET_FULL_DETAILS MESSAGE RETURNS THE DETAILS OF A GIVEN CLAIM
+HASH, STARTING WITH ITS CLAIMTYPE TO TELL YOU WHAT TYPE OF CLAIM IT WAS. 
+The ClaimType enum is SCALE encoded as the same code numbers (1-5) listed
+above, with 0 (Unknown) returned for a claim that does not exist. Messages 
+that take a ClaimType return InvalidClaimType for Unknown (0). A code above 5
+is not a ClaimType at all, so a call passing one fails when it is decoded, 
+before the message runs.
+
+NOTE: All claims are made through the make_claim message, which takes the
+ClaimType as its first argument and runs every claim type through the same
+validation, storage, event and reward pipeline. IP claims also pass the hash
+of their file. The uniquely titled make_claim_* messages from earlier versions 
+are kept as thin wrappers so existing front ends can still make claims, but 
+see CLAIM IDS below for the one breaking change. A uniquely titled event is still emitted
+for each use case so that we will know how to parse the u8 vector of claim
+metadata later in the front end. Only a single message and associated event
+type is needed for claim endorsements. 
+
+CLAIM IDS: A claim_id is the hash of a domain separator, the claimant, the 
+ClaimType and the claim keywords, so the same keywords can be claimed under 
+different claim types. Use the compute_claim_id message to work out a claim_id.
+BREAKING CHANGE: earlier versions used the file hash as the claim_id of an IP 
+claim, and make_claim_intellectualproperty now returns a claim_id worked out 
+like any other. Front ends that passed the file hash to endorse_claim, 
+get_full_details, show_or_hide_claim or any other message taking a claim_id 
+get NonexistentClaim; look the claim_id up with get_ip_claim_by_file_hash 
+or compute_claim_id instead.
+
+METADATA: Good deed and IP claims can be made with make_claim_with_metadata,
+which takes a versioned ClaimMetadata (GoodDeedV1 or IntellectualPropertyV1)
+instead of a free text u8 vector. The contract checks the metadata and builds 
+the claim keywords from it in the format listed above, and get_full_details 
+returns the decoded metadata, so front ends no longer need to parse the text.
+
+IP FILE HASHES: Every IP claim records the hash of its file, and the first 
+account to register a file hash keeps it. Later IP claims for the same file
+hash by other accounts are rejected. Use get_ip_claim_by_file_hash to find
+out whether a file has already been registered, and by whom.
+
+ISSUED CLAIMS: Employers, schools and other issuers can make a claim about 
+another account with issue_claim. The claim waits until that account accepts
+it (accept_issued_claim), and then lands in its resume with the issuer set in
+its Details. Self-asserted claims have no issuer.
+
+INSTITUTION REGISTRY: The registry admin (the account that deployed the 
+contract, until it hands over with set_registry_admin) curates a registry of
+verified employers, schools, NGOs and patent offices. Endorsements and issued
+claims from an institution that is verified at the time are flagged in the
+claim's Details (verified_endorsers and issuer_verified).
 */
 
 #![cfg_attr(not(feature = "std"), no_std, no_main)]
@@ -26,31 +67,101 @@ mod life_and_work {
     use ink::prelude::vec::Vec;
     use ink::prelude::vec;
     use ink::prelude::string::String;
+    use ink::prelude::string::ToString;
+    use ink::prelude::boxed::Box;
     use ink::storage::Mapping;
     use ink::storage::StorageVec;
     use ink::env::hash::{Sha2x256, HashOutput};
 
 
+    // hashed into every claim_id so that claim_ids made by this contract
+    // cannot collide with hashes of the same data made for other purposes
+    const CLAIM_ID_DOMAIN: &[u8] = b"life_and_work/claim_id/v2";
+    // the most claims a paged search will look at in one call
+    const MAX_SEARCH_SCAN: u32 = 500;
+    // the most matching claims a paged search will return in one call
+    const MAX_SEARCH_RESULTS: u32 = 25;
+    // the most keywords a paged search will accept
+    const MAX_SEARCH_KEYWORDS: usize = 10;
+    // the most distinct keywords a claim may have, so every one of them is in the keyword index
+    const MAX_INDEXED_KEYWORDS: usize = 64;
+    // the most issued claims that can wait for an account to answer them
+    const MAX_PENDING_ISSUED: u32 = 50;
+    // the most terms and phrases a search query may contain
+    const MAX_QUERY_TERMS: usize = 32;
+    // the deepest a search query may nest parentheses and NOTs
+    const MAX_QUERY_DEPTH: usize = 8;
+
+
     // PRELIMINARY DATA STRUCTURES >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>
 
     #[derive(Clone, Debug, PartialEq, Eq)]
     #[ink::scale_derive(Encode, Decode, TypeInfo)]
     #[cfg_attr(feature = "std",derive(ink::storage::traits::StorageLayout,))]
     pub struct Details {
-        claimtype: u8,
+        claimtype: ClaimType,
         claimant: AccountId,
         claim: Vec<u8>,
         claim_id: Hash,
         endorser_count: u128,
         link: Vec<u8>,
         show: bool,
-        endorsers: Vec<AccountId>
+        endorsers: Vec<AccountId>,
+        revision: u32,
+        earlier_endorsers: Vec<AccountId>,
+        created_at: Timestamp,
+        created_block: BlockNumber,
+        last_endorsed_at: Timestamp,
+        tenure: Option<Tenure>,
+        metadata: ClaimMetadata,
+        file_hash: Option<Hash>,
+        coauthors: Vec<CoAuthor>,
+        coauthors_confirmed: bool,
+        license_terms: Option<LicenseTerms>,
+        previous_owners: Vec<AccountId>,
+        beneficiary: Option<AccountId>,
+        deed_confirmed: bool,
+        impact: Option<DeedImpact>,
+        issuer: Option<AccountId>,
+        issuer_verified: bool,
+        verified_endorsers: Vec<AccountId>,
+        endorsement_round: u32,
+    }
+
+    impl Details {
+        // when a good deed was done: the start of the day in its metadata if it has 
+        // one, or else when the claim was made, as a block timestamp in milliseconds
+        fn deed_time(&self) -> Timestamp {
+            if let ClaimMetadata::GoodDeedV1(meta) = &self.metadata {
+                if let Some(days) = day_number(meta.date) {
+                    return u64::try_from(days).unwrap_or(0).saturating_mul(86_400_000)
+                }
+            }
+            self.created_at
+        }
+
+        // the highest verification level the claim has reached
+        fn verification_level(&self) -> VerificationLevel {
+            if self.issuer_verified || !self.verified_endorsers.is_empty() {
+                VerificationLevel::InstitutionVerified
+            }
+            else if self.issuer.is_some() || self.deed_confirmed 
+                || (!self.coauthors.is_empty() && self.coauthors_confirmed) {
+                VerificationLevel::ConfirmedByCounterparty
+            }
+            else if self.endorser_count > 0 {
+                VerificationLevel::PeerEndorsed(self.endorser_count)
+            }
+            else {
+                VerificationLevel::SelfAsserted
+            }
+        }
     }
 
     impl Default for Details {
         fn default() -> Details {
             Details {
-                claimtype: 0,
+                claimtype: ClaimType::default(),
                 claimant: AccountId::from([0x0; 32]),
                 claim: <Vec<u8>>::default(),
                 claim_id: Hash::default(),
@@ -58,27 +169,541 @@ mod life_and_work {
                 link: <Vec<u8>>::default(),
                 show: true,
                 endorsers: <Vec<AccountId>>::default(),
+                revision: 0,
+                earlier_endorsers: <Vec<AccountId>>::default(),
+                created_at: Timestamp::default(),
+                created_block: BlockNumber::default(),
+                last_endorsed_at: Timestamp::default(),
+                tenure: None,
+                metadata: ClaimMetadata::None,
+                file_hash: None,
+                coauthors: <Vec<CoAuthor>>::default(),
+                coauthors_confirmed: true,
+                license_terms: None,
+                previous_owners: <Vec<AccountId>>::default(),
+                beneficiary: None,
+                deed_confirmed: false,
+                impact: None,
+                issuer: None,
+                issuer_verified: false,
+                verified_endorsers: <Vec<AccountId>>::default(),
+                endorsement_round: 0,
             }
         }
     }
    
 
-    #[derive(Clone, Debug, PartialEq, Eq)]
+    // How well a claim is backed up, from lowest to highest rank:
+    // claimed with no backing, endorsed by N peers, confirmed by the other 
+    // party to it (a good deed's beneficiary, every co-author of an IP claim,
+    // or the issuer of an issued claim), or issued or endorsed by a verified 
+    // institution. Computed from the claim's Details whenever it is read.
+    #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    #[cfg_attr(feature = "std",derive(ink::storage::traits::StorageLayout,))]
+    pub enum VerificationLevel {
+        #[default]
+        SelfAsserted,
+        PeerEndorsed(u128),
+        ConfirmedByCounterparty,
+        InstitutionVerified,
+    }
+
+    impl VerificationLevel {
+        // the place of the level in the order above
+        fn rank(&self) -> u8 {
+            match self {
+                VerificationLevel::SelfAsserted => 0,
+                VerificationLevel::PeerEndorsed(_) => 1,
+                VerificationLevel::ConfirmedByCounterparty => 2,
+                VerificationLevel::InstitutionVerified => 3,
+            }
+        }
+
+        // is this level at least the minimum? Peer endorsements also need 
+        // at least as many endorsers as the minimum asks for.
+        fn meets(&self, min: &VerificationLevel) -> bool {
+            match (self, min) {
+                (VerificationLevel::PeerEndorsed(count), VerificationLevel::PeerEndorsed(min_count)) => count >= min_count,
+                _ => self.rank() >= min.rank(),
+            }
+        }
+    }
+
+
+    // A claim's details with its verification level
+    #[derive(Clone, Debug, Default, PartialEq, Eq)]
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    #[cfg_attr(feature = "std",derive(ink::storage::traits::StorageLayout,))]
+    pub struct VerifiedDetails {
+        details: Details,
+        level: VerificationLevel,
+    }
+
+
+    // The kinds of claim this contract stores. The discriminants are the
+    // claim type codes used since the first version of the contract, so 
+    // claims stored with a u8 code decode straight into a ClaimType.
+    #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    #[cfg_attr(feature = "std",derive(ink::storage::traits::StorageLayout,))]
+    pub enum ClaimType {
+        // the type reported for a claim that does not exist, never stored
+        #[default]
+        Unknown = 0,
+        WorkHistory = 1,
+        Education = 2,
+        Expertise = 3,
+        GoodDeed = 4,
+        IntellectualProperty = 5,
+    }
+
+    impl ClaimType {
+        // every storable claim type, in the order they appear in a resume
+        pub const ALL: [ClaimType; 5] = [
+            ClaimType::WorkHistory,
+            ClaimType::Education,
+            ClaimType::Expertise,
+            ClaimType::GoodDeed,
+            ClaimType::IntellectualProperty,
+        ];
+
+        // the most claims of this type kept in an account's resume
+        // before the oldest is kicked out
+        fn max_account_claims(self) -> usize {
+            match self {
+                ClaimType::WorkHistory => 10,
+                _ => 20,
+            }
+        }
+    }
+
+
+    #[derive(Clone, Debug, Default, PartialEq, Eq)]
     #[ink::scale_derive(Encode, Decode, TypeInfo)]
     #[cfg_attr(feature = "std",derive(ink::storage::traits::StorageLayout,))]
     pub struct Claims {
         claims: Vec<Hash>
     }
 
-    impl Default for Claims {
-        fn default() -> Claims {
-            Claims {
-                claims: <Vec<Hash>>::default(),
+
+    // One page of search results. If next_index is Some, pass it as the start_index 
+    // of the next call to continue the search where this page left off.
+    #[derive(Clone, Debug, Default, PartialEq, Eq)]
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    #[cfg_attr(feature = "std",derive(ink::storage::traits::StorageLayout,))]
+    pub struct SearchPage {
+        results: Vec<Details>,
+        next_index: Option<u32>,
+    }
+
+
+    // Where a search across several claim types is up to: the claim type 
+    // being searched and the index to continue from in its search vector
+    #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    #[cfg_attr(feature = "std",derive(ink::storage::traits::StorageLayout,))]
+    pub struct SearchCursor {
+        claim_type: ClaimType,
+        index: u32,
+    }
+
+
+    // One page of results from a search across several claim types. Each result 
+    // is tagged with its type in Details.claimtype. If next is Some, pass it as 
+    // the start of the next call to continue the search where this page left off.
+    #[derive(Clone, Debug, Default, PartialEq, Eq)]
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    #[cfg_attr(feature = "std",derive(ink::storage::traits::StorageLayout,))]
+    pub struct MultiSearchPage {
+        results: Vec<Details>,
+        next: Option<SearchCursor>,
+    }
+
+
+    // How search keywords are compared to the claim keywords
+    #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    #[cfg_attr(feature = "std",derive(ink::storage::traits::StorageLayout,))]
+    pub enum MatchMode {
+        // the claim must contain the keywords exactly as given
+        #[default]
+        Exact,
+        // keywords and claims are both lowercased, stripped of punctuation and 
+        // have their whitespace collapsed before they are compared
+        Normalized,
+    }
+
+
+    // The order search results are returned in
+    #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    #[cfg_attr(feature = "std",derive(ink::storage::traits::StorageLayout,))]
+    pub enum SortOrder {
+        // in the order the claims were added
+        #[default]
+        OldestFirst,
+        // the most recently added claims first
+        NewestFirst,
+        // the claims with the most endorsers first, out of every claim a call looks at
+        MostEndorsed,
+    }
+
+
+    // Sorting and filtering options for the search messages
+    #[derive(Clone, Debug, Default, PartialEq, Eq)]
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    #[cfg_attr(feature = "std",derive(ink::storage::traits::StorageLayout,))]
+    pub struct SearchOptions {
+        mode: MatchMode,
+        sort: SortOrder,
+        // leave out claims with fewer endorsers than this
+        min_endorsements: u128,
+        // leave out claims made before this block timestamp
+        created_after: Option<Timestamp>,
+        // leave out claims made after this block timestamp
+        created_before: Option<Timestamp>,
+        // leave out everything but good deeds confirmed by their beneficiary
+        confirmed_only: bool,
+        // Some(true) for only issued claims, Some(false) for only self-asserted claims
+        issued: Option<bool>,
+        // leave out claims below this verification level
+        min_verification: Option<VerificationLevel>,
+    }
+
+    impl SearchOptions {
+        // does the claim pass the filters?
+        fn accepts(&self, details: &Details) -> bool {
+            details.endorser_count >= self.min_endorsements
+            && self.created_after.is_none_or(|after| details.created_at >= after)
+            && self.created_before.is_none_or(|before| details.created_at <= before)
+            && (!self.confirmed_only || details.deed_confirmed)
+            && self.issued.is_none_or(|issued| details.issuer.is_some() == issued)
+            && self.min_verification.as_ref().is_none_or(|min| details.verification_level().meets(min))
+        }
+
+        // put a page of results in the requested order. Recency mostly comes from 
+        // the direction of the scan, but retracted claims leave the search vectors 
+        // slightly out of order, so the page is sorted by created_at as well.
+        fn rank(&self, results: &mut [Details]) {
+            match self.sort {
+                SortOrder::OldestFirst => results.sort_by_key(|details| details.created_at),
+                SortOrder::NewestFirst => results.sort_by_key(|details| core::cmp::Reverse(details.created_at)),
+                SortOrder::MostEndorsed => results.sort_by_key(|details| core::cmp::Reverse(details.endorser_count)),
+            }
+        }
+    }
+
+
+    // The lists of claim_ids a search can walk through
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    enum ClaimList {
+        // the all_claims StorageVec for a claim type
+        AllClaims(ClaimType),
+        // one of the claim index lists
+        Index(IndexKey),
+    }
+
+
+    // The lists of claim_ids kept in the contract's claim indexes. Each list 
+    // is stored like a StorageVec under its IndexKey.
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    #[cfg_attr(feature = "std",derive(ink::storage::traits::StorageLayout,))]
+    pub enum IndexKey {
+        // the claims of a type with a keyword, by the hash of the normalized keyword
+        Keyword(ClaimType, Hash),
+        // the IP claims an account has confirmed it co-authored
+        CoAuthored(AccountId),
+        // the IP claims an account holds a license for
+        Licensed(AccountId),
+        // the good deed claims that name an account as their beneficiary
+        Beneficiary(AccountId),
+        // every good deed claim an account has made
+        Volunteer(AccountId),
+        // the issued claims waiting for an account to accept or reject them
+        PendingIssued(AccountId),
+    }
+
+
+    // The structured dates, organization and role (or degree) of a work history 
+    // or education claim. Dates are YYYYMMDD numbers, such as 20240131, and an 
+    // end_date of None means the position or study is current.
+    #[derive(Clone, Debug, Default, PartialEq, Eq)]
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    #[cfg_attr(feature = "std",derive(ink::storage::traits::StorageLayout,))]
+    pub struct Tenure {
+        organization: Vec<u8>,
+        role_or_degree: Vec<u8>,
+        start_date: u32,
+        end_date: Option<u32>,
+    }
+
+    impl Tenure {
+        // check the tenure belongs on this claim type, fits in storage and has 
+        // real dates that end on or after they start
+        fn validate(&self, claim_type: ClaimType) -> Result<(), Error> {
+            if claim_type != ClaimType::WorkHistory && claim_type != ClaimType::Education {
+                return Err(Error::InvalidClaimType)
+            }
+            if self.organization.len() > 200 || self.role_or_degree.len() > 200 {
+                return Err(Error::DataTooLarge)
+            }
+            let start = day_number(self.start_date).ok_or(Error::InvalidDateRange)?;
+            if let Some(end_date) = self.end_date {
+                let end = day_number(end_date).ok_or(Error::InvalidDateRange)?;
+                if end < start {
+                    return Err(Error::InvalidDateRange)
+                }
+            }
+            Ok(())
+        }
+    }
+
+
+    // A gap in an account's timeline between the end of one claim's tenure 
+    // (after) and the start of the next (before), as YYYYMMDD dates
+    #[derive(Clone, Debug, Default, PartialEq, Eq)]
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    #[cfg_attr(feature = "std",derive(ink::storage::traits::StorageLayout,))]
+    pub struct TimelineGap {
+        after: u32,
+        before: u32,
+    }
+
+
+    // An account's work history or education claims that have a tenure, 
+    // in order of start date, with the gaps between them
+    #[derive(Clone, Debug, Default, PartialEq, Eq)]
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    #[cfg_attr(feature = "std",derive(ink::storage::traits::StorageLayout,))]
+    pub struct Timeline {
+        entries: Vec<Details>,
+        gaps: Vec<TimelineGap>,
+    }
+
+
+    // The structured fields of a good deed claim. The date is a YYYYMMDD number.
+    #[derive(Clone, Debug, Default, PartialEq, Eq)]
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    #[cfg_attr(feature = "std",derive(ink::storage::traits::StorageLayout,))]
+    pub struct GoodDeedMeta {
+        beneficiary: Vec<u8>,
+        date: u32,
+        location: Vec<u8>,
+        keywords: Vec<u8>,
+    }
+
+
+    // The structured fields of an original IP claim
+    #[derive(Clone, Debug, Default, PartialEq, Eq)]
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    #[cfg_attr(feature = "std",derive(ink::storage::traits::StorageLayout,))]
+    pub struct IpMeta {
+        title: Vec<u8>,
+        keywords: Vec<u8>,
+        file_hash: Hash,
+    }
+
+
+    // The structured metadata of a claim. Each variant is versioned, so a 
+    // later format gets a new variant rather than changing an existing one,
+    // and the codec indexes must never be reused.
+    #[derive(Clone, Debug, Default, PartialEq, Eq)]
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    #[cfg_attr(feature = "std",derive(ink::storage::traits::StorageLayout,))]
+    pub enum ClaimMetadata {
+        #[default]
+        #[codec(index = 0)]
+        None,
+        #[codec(index = 1)]
+        GoodDeedV1(GoodDeedMeta),
+        #[codec(index = 2)]
+        IntellectualPropertyV1(IpMeta),
+    }
+
+    impl ClaimMetadata {
+        // check the metadata belongs on this claim type, has its required 
+        // fields and fits in storage
+        fn validate(&self, claim_type: ClaimType) -> Result<(), Error> {
+            match self {
+                ClaimMetadata::None => Ok(()),
+                ClaimMetadata::GoodDeedV1(meta) => {
+                    if claim_type != ClaimType::GoodDeed || meta.beneficiary.is_empty() {
+                        return Err(Error::InvalidMetadata)
+                    }
+                    if meta.beneficiary.len() > 150 || meta.location.len() > 150 
+                        || meta.keywords.len() > 250 {
+                        return Err(Error::DataTooLarge)
+                    }
+                    day_number(meta.date).ok_or(Error::InvalidDateRange)?;
+                    Ok(())
+                },
+                ClaimMetadata::IntellectualPropertyV1(meta) => {
+                    if claim_type != ClaimType::IntellectualProperty || meta.title.is_empty() {
+                        return Err(Error::InvalidMetadata)
+                    }
+                    if meta.title.len() > 200 || meta.keywords.len() > 300 {
+                        return Err(Error::DataTooLarge)
+                    }
+                    Ok(())
+                },
+            }
+        }
+
+        // the claim keywords in the documented format for the claim type, 
+        // so keyword search and older front ends keep working:
+        // beneficiary - date - location - keywords, or title - keywords - file hash
+        fn claim_keywords(&self) -> Vec<u8> {
+            let mut parts: Vec<Vec<u8>> = Vec::new();
+            match self {
+                ClaimMetadata::None => {},
+                ClaimMetadata::GoodDeedV1(meta) => {
+                    parts.push(meta.beneficiary.clone());
+                    parts.push(Vec::from(meta.date.to_string().as_bytes()));
+                    parts.push(meta.location.clone());
+                    parts.push(meta.keywords.clone());
+                },
+                ClaimMetadata::IntellectualPropertyV1(meta) => {
+                    parts.push(meta.title.clone());
+                    parts.push(meta.keywords.clone());
+                    parts.push(hex_bytes(meta.file_hash.as_ref()));
+                },
+            }
+            parts.join(&b" - "[..])
+        }
+
+        // the hash of the IP file, for IP metadata
+        fn file_hash(&self) -> Option<Hash> {
+            match self {
+                ClaimMetadata::IntellectualPropertyV1(meta) => Some(meta.file_hash),
+                _ => None,
+            }
+        }
+    }
+
+
+    // A co-author of an IP claim and their percentage share of it. The claimant
+    // holds whatever share the co-authors do not. A co-author is confirmed once 
+    // they countersign the claim with confirm_coauthorship.
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    #[cfg_attr(feature = "std",derive(ink::storage::traits::StorageLayout,))]
+    pub struct CoAuthor {
+        account: AccountId,
+        share: u8,
+        confirmed: bool,
+    }
+
+
+    // The terms on which an IP claim can be licensed. The price is in the 
+    // native balance and is paid to the claimant and co-authors by share.
+    #[derive(Clone, Debug, Default, PartialEq, Eq)]
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    #[cfg_attr(feature = "std",derive(ink::storage::traits::StorageLayout,))]
+    pub struct LicenseTerms {
+        license_id: Vec<u8>,
+        price: Balance,
+        transferable: bool,
+    }
+
+
+    // A license to an IP claim held by an account, on the terms it was bought under
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    #[cfg_attr(feature = "std",derive(ink::storage::traits::StorageLayout,))]
+    pub struct License {
+        claim_id: Hash,
+        licensee: AccountId,
+        license_id: Vec<u8>,
+        price_paid: Balance,
+        transferable: bool,
+        granted_at: Timestamp,
+    }
+
+
+    // The measurable impact of a good deed: the hours volunteered and the 
+    // quantity delivered, such as meals served or trees planted
+    #[derive(Clone, Debug, Default, PartialEq, Eq)]
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    #[cfg_attr(feature = "std",derive(ink::storage::traits::StorageLayout,))]
+    pub struct DeedImpact {
+        hours: u32,
+        quantity: u32,
+    }
+
+
+    // The total impact of the confirmed good deeds done in a time window.
+    // next_index is where to continue the total, or None when done.
+    #[derive(Clone, Debug, Default, PartialEq, Eq)]
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    #[cfg_attr(feature = "std",derive(ink::storage::traits::StorageLayout,))]
+    pub struct ImpactSummary {
+        deeds: u32,
+        hours: u64,
+        quantity: u64,
+        next_index: Option<u32>,
+    }
+
+    impl ImpactSummary {
+        // add a good deed to the total if it is visible, confirmed by its 
+        // beneficiary and done in the window
+        fn add(&mut self, details: &Details, done_after: Option<Timestamp>, done_before: Option<Timestamp>) {
+            let done_at = details.deed_time();
+            if !details.show || !details.deed_confirmed
+                || done_after.is_some_and(|after| done_at < after)
+                || done_before.is_some_and(|before| done_at > before) {
+                return
             }
+            let impact = details.impact.clone().unwrap_or_default();
+            self.deeds = self.deeds.saturating_add(1);
+            self.hours = self.hours.saturating_add(u64::from(impact.hours));
+            self.quantity = self.quantity.saturating_add(u64::from(impact.quantity));
         }
     }
 
 
+    // The kinds of organization in the institution registry
+    #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    #[cfg_attr(feature = "std",derive(ink::storage::traits::StorageLayout,))]
+    pub enum InstitutionCategory {
+        #[default]
+        Employer,
+        School,
+        Ngo,
+        PatentOffice,
+    }
+
+
+    // An organization in the registry of verified institutions. A suspended 
+    // institution stays in the registry but is no longer treated as verified.
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    #[cfg_attr(feature = "std",derive(ink::storage::traits::StorageLayout,))]
+    pub struct Institution {
+        account: AccountId,
+        name: Vec<u8>,
+        category: InstitutionCategory,
+        link: Vec<u8>,
+        suspended: bool,
+    }
+
+
+    // A previous version of an amended claim, as it stood before the amendment
+    #[derive(Clone, Debug, Default, PartialEq, Eq)]
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    #[cfg_attr(feature = "std",derive(ink::storage::traits::StorageLayout,))]
+    pub struct Revision {
+        revision: u32,
+        claim: Vec<u8>,
+        link: Vec<u8>,
+        endorser_count: u128,
+        endorsers: Vec<AccountId>,
+        metadata: ClaimMetadata,
+    }
+
+
     #[derive(Clone, Debug, PartialEq, Eq)]
     #[ink::scale_derive(Encode, Decode, TypeInfo)]
     #[cfg_attr(feature = "std",derive(ink::storage::traits::StorageLayout,))]
@@ -174,54 +799,258 @@ mod life_and_work {
         #[ink(topic)]
         claim_id: Hash,
         #[ink(topic)]
-        endorser: AccountId
+        endorser: AccountId,
+        claim_type: ClaimType,
     }
 
     #[ink(event)]
-    // Writes the new reward to the blockchain 
-    pub struct AccountRewardedLifeAndWork {
+    // Writes the amendment of a claim to the blockchain 
+    pub struct ClaimAmended {
         #[ink(topic)]
         claimant: AccountId,
-        reward: Balance,
+        #[ink(topic)]
+        claim_id: Hash,
+        revision: u32,
+        endorsements_carried_over: bool,
     }
 
+    #[ink(event)]
+    // Writes the retraction of a claim to the blockchain 
+    pub struct ClaimRetracted {
+        #[ink(topic)]
+        claimant: AccountId,
+        #[ink(topic)]
+        claim_id: Hash,
+        claim_type: ClaimType,
+    }
 
-    // ERROR DEFINITIONS >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>
+    #[ink(event)]
+    // Writes the co-authors listed on an IP claim to the blockchain 
+    pub struct CoAuthorsSet {
+        #[ink(topic)]
+        claimant: AccountId,
+        #[ink(topic)]
+        claim_id: Hash,
+        coauthors: Vec<AccountId>,
+    }
 
-    // Errors that can occur upon calling this contract...
-    #[derive(Debug, PartialEq, Eq)]
-    #[ink::scale_derive(Encode, Decode, TypeInfo)]
-    pub enum Error {
-        // Returned if the new claim already exists.
-        DuplicateClaim,
-        // Returned if the endorsed claim does not exist.
-        NonexistentClaim,
-        // Returned if the caller has alredy endorsed this claim.
-        DuplicateEndorsement,
-        // Returned if the caller is not the owner of a claim.
-        CallerNotOwner,
-        // input data is too large
-        DataTooLarge,
-        // Caller doee not have permission
-        PermissionDenied,
-        // payout failed to root for reward program shut down
-        PayoutFailed,
-        // zero balance or not enough in the reward program
-        ZeroBalance,
+    #[ink(event)]
+    // Writes a co-author's countersignature of an IP claim to the blockchain 
+    pub struct CoAuthorshipConfirmed {
+        #[ink(topic)]
+        coauthor: AccountId,
+        #[ink(topic)]
+        claim_id: Hash,
+        fully_confirmed: bool,
     }
 
+    #[ink(event)]
+    // Writes the sale of a license to an IP claim to the blockchain 
+    pub struct LicenseGranted {
+        #[ink(topic)]
+        claim_id: Hash,
+        #[ink(topic)]
+        licensee: AccountId,
+        license_id: Vec<u8>,
+        price_paid: Balance,
+    }
 
-    // CONTRACT LOGIC >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>
+    #[ink(event)]
+    // Writes the transfer of a license to an IP claim to the blockchain 
+    pub struct LicenseTransferred {
+        #[ink(topic)]
+        claim_id: Hash,
+        #[ink(topic)]
+        from: AccountId,
+        #[ink(topic)]
+        to: AccountId,
+    }
 
-    // ACTUAL CONTRACT STORAGE >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>
-    #[ink(storage)]
-    pub struct ContractStorage {
-        all_claims_expertise: StorageVec<Hash>,
-        all_claims_education: StorageVec<Hash>,
-        all_claims_work: StorageVec<Hash>,
-        all_claims_deeds: StorageVec<Hash>,
-        all_claims_ip: StorageVec<Hash>,
-        claim_details: Mapping<Hash, Details>,
+    #[ink(event)]
+    // Writes an offer to transfer an IP claim to another account to the blockchain 
+    pub struct IpTransferOffered {
+        #[ink(topic)]
+        claim_id: Hash,
+        #[ink(topic)]
+        from: AccountId,
+        #[ink(topic)]
+        to: AccountId,
+    }
+
+    #[ink(event)]
+    // Writes the withdrawal of an offer to transfer an IP claim to the blockchain 
+    pub struct IpTransferCancelled {
+        #[ink(topic)]
+        claim_id: Hash,
+        #[ink(topic)]
+        from: AccountId,
+        #[ink(topic)]
+        to: AccountId,
+    }
+
+    #[ink(event)]
+    // Writes the completed transfer of an IP claim to the blockchain 
+    pub struct IpTransferAccepted {
+        #[ink(topic)]
+        claim_id: Hash,
+        #[ink(topic)]
+        from: AccountId,
+        #[ink(topic)]
+        to: AccountId,
+    }
+
+    #[ink(event)]
+    // Writes a beneficiary's confirmation of a good deed to the blockchain 
+    pub struct GoodDeedConfirmed {
+        #[ink(topic)]
+        beneficiary: AccountId,
+        #[ink(topic)]
+        claim_id: Hash,
+    }
+
+    #[ink(event)]
+    // Writes a claim issued to an account, waiting for its acceptance, to the blockchain 
+    pub struct ClaimIssued {
+        #[ink(topic)]
+        issuer: AccountId,
+        #[ink(topic)]
+        subject: AccountId,
+        #[ink(topic)]
+        claim_id: Hash,
+        claim_type: ClaimType,
+    }
+
+    #[ink(event)]
+    // Writes the subject's answer to an issued claim to the blockchain 
+    pub struct IssuedClaimAnswered {
+        #[ink(topic)]
+        subject: AccountId,
+        #[ink(topic)]
+        claim_id: Hash,
+        accepted: bool,
+    }
+
+    #[ink(event)]
+    // Writes an institution added to or updated in the registry to the blockchain 
+    pub struct InstitutionRegistered {
+        #[ink(topic)]
+        account: AccountId,
+        name: Vec<u8>,
+        category: InstitutionCategory,
+    }
+
+    #[ink(event)]
+    // Writes the suspension or reinstatement of an institution to the blockchain 
+    pub struct InstitutionSuspended {
+        #[ink(topic)]
+        account: AccountId,
+        suspended: bool,
+    }
+
+    #[ink(event)]
+    // Writes the withdrawal of an endorsement to the blockchain 
+    pub struct EndorsementRevoked {
+        #[ink(topic)]
+        claimant: AccountId,
+        #[ink(topic)]
+        claim_id: Hash,
+        #[ink(topic)]
+        endorser: AccountId,
+        claim_type: ClaimType,
+    }
+
+    #[ink(event)]
+    // Writes the new reward to the blockchain 
+    pub struct AccountRewardedLifeAndWork {
+        #[ink(topic)]
+        claimant: AccountId,
+        reward: Balance,
+    }
+
+
+    // ERROR DEFINITIONS >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>
+
+    // Errors that can occur upon calling this contract...
+    #[derive(Debug, PartialEq, Eq)]
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    pub enum Error {
+        // Returned if the new claim already exists.
+        DuplicateClaim,
+        // Returned if the endorsed claim does not exist.
+        NonexistentClaim,
+        // Returned if the caller has alredy endorsed this claim.
+        DuplicateEndorsement,
+        // Returned if the caller is not the owner of a claim.
+        CallerNotOwner,
+        // input data is too large
+        DataTooLarge,
+        // Caller doee not have permission
+        PermissionDenied,
+        // payout failed to root for reward program shut down
+        PayoutFailed,
+        // zero balance or not enough in the reward program
+        ZeroBalance,
+        // Returned if the claim type is Unknown, or not allowed for what was asked
+        InvalidClaimType,
+        // Returned if the new claim was retracted and cannot be made again
+        RetractedClaim,
+        // Returned if a search query cannot be parsed or is too complex
+        InvalidQuery,
+        // Returned if a date is not a real YYYYMMDD date or a date range ends before it starts
+        InvalidDateRange,
+        // Returned if the claim metadata does not suit the claim type or is missing a required field
+        InvalidMetadata,
+        // Returned if another account registered an IP claim for this file hash first
+        FileHashRegistered,
+        // Returned if co-author shares add up to over 100, or a co-author is the claimant or listed twice
+        InvalidShares,
+        // Returned if the IP claim has no license terms, so no license can be bought
+        NotLicensable,
+        // Returned if the payment is less than the license price
+        InsufficientPayment,
+        // Returned if the account already holds a license, or the license cannot be transferred
+        LicenseUnavailable,
+        // Returned if the account is not in the institution registry
+        UnknownInstitution,
+        // Returned if the caller is not one of the claim's current endorsers
+        NotEndorser,
+        // Returned if the caller revoked their endorsement of this claim, see reendorse_claim
+        RevokedEndorsement,
+        // Returned if an IP claim is made without the hash of its file
+        MissingFileHash,
+        // Returned if the subject of an issued claim already rejected it
+        RejectedClaim,
+    }
+
+
+    // CONTRACT LOGIC >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>
+
+    // ACTUAL CONTRACT STORAGE >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>
+    #[ink(storage)]
+    pub struct ContractStorage {
+        all_claims_expertise: StorageVec<Hash>,
+        all_claims_education: StorageVec<Hash>,
+        all_claims_work: StorageVec<Hash>,
+        all_claims_deeds: StorageVec<Hash>,
+        all_claims_ip: StorageVec<Hash>,
+        all_claims_positions: Mapping<Hash, u32>,
+        claim_details: Mapping<Hash, Details>,
+        claim_revisions: Mapping<(Hash, u32), Revision>,
+        retracted_claims: Mapping<Hash, AccountId>,
+        normalized_claims: Mapping<Hash, String>,
+        index_len: Mapping<IndexKey, u32>,
+        index_items: Mapping<(IndexKey, u32), Hash>,
+        index_positions: Mapping<(IndexKey, Hash), u32>,
+        ip_file_hashes: Mapping<Hash, Hash>,
+        licenses: Mapping<(Hash, AccountId), License>,
+        ip_transfer_offers: Mapping<Hash, AccountId>,
+        issued_claims: Mapping<Hash, Details>,
+        rejected_claims: Mapping<Hash, AccountId>,
+        registry_admin: AccountId,
+        institutions: Mapping<AccountId, Institution>,
+        institution_accounts: StorageVec<AccountId>,
+        revoked_endorsements: Mapping<(Hash, AccountId), Timestamp>,
+        endorsements: Mapping<(Hash, AccountId), u32>,
         account_claims_expertise: Mapping<AccountId, Claims>,
         account_claims_education: Mapping<AccountId, Claims>,
         account_claims_workhistory: Mapping<AccountId, Claims>,
@@ -250,7 +1079,24 @@ mod life_and_work {
                 all_claims_work: StorageVec::default(),
                 all_claims_deeds: StorageVec::default(),
                 all_claims_ip: StorageVec::default(),
+                all_claims_positions: Mapping::default(),
                 claim_details: Mapping::default(),
+                claim_revisions: Mapping::default(),
+                retracted_claims: Mapping::default(),
+                normalized_claims: Mapping::default(),
+                index_len: Mapping::default(),
+                index_items: Mapping::default(),
+                index_positions: Mapping::default(),
+                ip_file_hashes: Mapping::default(),
+                licenses: Mapping::default(),
+                ip_transfer_offers: Mapping::default(),
+                issued_claims: Mapping::default(),
+                rejected_claims: Mapping::default(),
+                registry_admin: Self::env().caller(),
+                institutions: Mapping::default(),
+                institution_accounts: StorageVec::default(),
+                revoked_endorsements: Mapping::default(),
+                endorsements: Mapping::default(),
                 account_claims_expertise: Mapping::default(),
                 account_claims_education: Mapping::default(),
                 account_claims_workhistory: Mapping::default(),
@@ -269,493 +1115,186 @@ mod life_and_work {
 
 
         // MESSAGE FUNCTIONS THAT ALTER CONTRACT STORAGE >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>
-        
+
         #[ink(message)]
-        // 🟢 0 EXPERTISE - Updates the storage map and emits an event to register the claim on chain
-        pub fn make_claim_expertise(&mut self, 
-            keywords_or_description: Vec<u8>, url_link_to_see_more: Vec<u8>
+        // 🟢 17 MAKE CLAIM - Updates the storage map and emits an event to register a claim of any type on chain.
+        // IP claims must pass the hash of their file as file_hash, and the other claim types None.
+        pub fn make_claim(&mut self, claim_type: ClaimType,
+            keywords_or_description: Vec<u8>, url_link_to_see_more: Vec<u8>, file_hash: Option<Hash>
+        ) -> Result<(), Error> {
+            // send the claim through the shared claim pipeline
+            let mut new_details = Self::draft_claim(Self::env().caller(), claim_type, keywords_or_description, url_link_to_see_more);
+            new_details.file_hash = file_hash;
+            self.store_claim(new_details)
+        }
+
+
+        #[ink(message)]
+        // 🟢 28 MAKE CLAIM WITH TENURE - make a work history or education claim with 
+        // structured dates, organization and role or degree
+        pub fn make_claim_with_tenure(&mut self, claim_type: ClaimType,
+            keywords_or_description: Vec<u8>, url_link_to_see_more: Vec<u8>, tenure: Tenure
+        ) -> Result<(), Error> {
+            let mut new_details = Self::draft_claim(Self::env().caller(), claim_type, keywords_or_description, url_link_to_see_more);
+            new_details.tenure = Some(tenure);
+            self.store_claim(new_details)
+        }
+
+
+        #[ink(message)]
+        // 🟢 31 MAKE CLAIM WITH METADATA - make a good deed or IP claim from structured 
+        // metadata. The claim keywords are built from the metadata in the documented format.
+        pub fn make_claim_with_metadata(&mut self, claim_type: ClaimType,
+            metadata: ClaimMetadata, url_link_to_see_more: Vec<u8>
         ) -> Result<(), Error> {
+            if metadata == ClaimMetadata::None {
+                return Err(Error::InvalidMetadata)
+            }
+            metadata.validate(claim_type)?;
+            let mut new_details = Self::draft_claim(Self::env().caller(), claim_type, metadata.claim_keywords(), url_link_to_see_more);
+            new_details.file_hash = metadata.file_hash();
+            new_details.metadata = metadata;
+            self.store_claim(new_details)
+        }
+
 
-            // define the caller...
+        #[ink(message)]
+        // 🟢 51 ISSUE CLAIM - As an employer, school or other issuer, make a claim about 
+        // another account. The claim waits until the subject accepts it, and only then
+        // goes through the claim pipeline into their resume. Returns the claim_id.
+        // Work history and education claims can be issued with a tenure. IP claims 
+        // cannot be issued, as they need the hash of their file. An account can have 
+        // at most 50 issued claims waiting for an answer.
+        pub fn issue_claim(&mut self, subject: AccountId, claim_type: ClaimType,
+            keywords_or_description: Vec<u8>, url_link_to_see_more: Vec<u8>, tenure: Option<Tenure>
+        ) -> Result<Hash, Error> {
             let caller = Self::env().caller();
-            // get the current set of claims for this account
-            let mut currentclaims = self.account_claims_expertise.get(caller).unwrap_or_default();
-            // if the data is too big send an error
-            if keywords_or_description.len() > 600 || url_link_to_see_more.len() > 600 {
+            if subject == caller {
+                return Err(Error::PermissionDenied)
+            }
+
+            // the claim_id includes the issuer, so it does not collide with a self-asserted claim
+            let mut new_details = Self::draft_claim(subject, claim_type, keywords_or_description, url_link_to_see_more);
+            let claim_hash = Self::hash_issued_claim(caller, subject, claim_type, &new_details.claim);
+            new_details.claim_id = claim_hash;
+            new_details.issuer = Some(caller);
+            new_details.issuer_verified = self.is_verified_institution(caller);
+            new_details.tenure = tenure;
+            self.check_claim(&new_details)?;
+            if self.issued_claims.contains(claim_hash) {
+                return Err(Error::DuplicateClaim)
+            }
+            if self.rejected_claims.contains(claim_hash) {
+                return Err(Error::RejectedClaim)
+            }
+            // the subject has to answer some of their pending claims before more can be issued
+            if self.index_len.get(IndexKey::PendingIssued(subject)).unwrap_or(0) >= MAX_PENDING_ISSUED {
                 return Err(Error::DataTooLarge)
             }
-            else {
-                // set up the data that will go into the claim_hash
-                let claimant = Self::env().caller();
-                let claim_contents = keywords_or_description.clone();
-
-                // create the claim_hash by hashing the claimant and claim data
-                let encodable = (claimant, claim_contents); // Implements `scale::Encode`
-                let mut claim_hash_u8 = <Sha2x256 as HashOutput>::Type::default(); // 256-bit buffer
-                ink::env::hash_encoded::<Sha2x256, _>(&encodable, &mut claim_hash_u8);
-                let claim_hash: Hash = Hash::from(claim_hash_u8);
-
-                // Check to make sure the claim is not a duplicate
-                if self.claim_details.contains(claim_hash) {
-                    // if TRUE, issue an error
-                    return Err(Error::DuplicateClaim)
-                }
-                else {
-                    // set the contract storage for this claim...
-                    let new_details = Details {
-                        claimtype: 3,
-                        claimant: Self::env().caller(),
-                        claim: keywords_or_description,
-                        claim_id: claim_hash,
-                        endorser_count: 0,
-                        link: url_link_to_see_more,
-                        show: true,
-                        endorsers: vec![Self::env().caller()]
-                    };
-
-                    // add this claim to the claim_details map
-                    if self.claim_details.try_insert(claim_hash, &new_details).is_err() {
-                        return Err(Error::DataTooLarge);
-                    }
-                    
-                    // add this claim hash to the set of claims for this account
-                    // if there are already 20 claims, kick out the oldest
-                    if currentclaims.claims.len() > 19 {
-                        currentclaims.claims.remove(0);
-                    }
-                    // add the claim hash to the Claims.claims vector of claim_id hashes
-                    currentclaims.claims.push(claim_hash);
-
-                    // update the account_claims mapping
-                    self.account_claims_expertise.insert(caller, &currentclaims);
-
-                    // add this event to the all_claims StorageVec
-                    self.all_claims_expertise.push(&claim_hash);
-
-                    // Emit an event to register the claim to the chain
-                    // make a clone of claim_meta 
-                    let claim_meta_clone = new_details.claim.clone();
-                    Self::env().emit_event(ClaimMadeExpertise {
-                        claimant: Self::env().caller(),
-                        claim: claim_meta_clone,
-                        claim_id: claim_hash
-                    });
-
-                    // REWARD PROGRAM ACTIONS... update the claim_counter 
-                    self.claim_counter = self.claim_counter.wrapping_add(1);
-                    // IF conditions are met THEN payout a reward
-                    let min = self.reward_amount.saturating_add(10);
-                    let payout: Balance = self.reward_amount;
-                    if self.reward_on == 1 && self.reward_balance > payout && self.env().balance() > min
-                    && self.claim_counter.checked_rem_euclid(self.reward_interval) == Some(0) {
-                        // payout
-                        if self.env().transfer(caller, payout).is_err() {
-                            return Err(Error::PayoutFailed);
-                        }
-                        // update reward_balance
-                        self.reward_balance = self.reward_balance.saturating_sub(payout);
-                        // update reward_payouts
-                        self.reward_payouts = self.reward_payouts.saturating_add(payout);
-                        // emit an event to register the reward to the chain
-                        Self::env().emit_event(AccountRewardedLifeAndWork {
-                            claimant: caller,
-                            reward: payout
-                        });
-                    }
-                    // END REWARD PROGRAM ACTIONS
 
-                }
+            // hold the claim until the subject answers
+            if self.issued_claims.try_insert(claim_hash, &new_details).is_err() {
+                return Err(Error::DataTooLarge);
             }
-            
-            Ok(())
+            self.index_add(IndexKey::PendingIssued(subject), claim_hash);
+
+            // emit an event to register the issued claim to the chain
+            Self::env().emit_event(ClaimIssued {
+                issuer: caller,
+                subject,
+                claim_id: claim_hash,
+                claim_type,
+            });
+
+            Ok(claim_hash)
         }
 
 
         #[ink(message)]
-        // 🟢 1 WORK - Updates the storage map and emits an event to register the claim on chain
-        pub fn make_claim_workhistory(&mut self, 
-            keywords_or_description: Vec<u8>, url_link_to_see_more: Vec<u8>
-        ) -> Result<(), Error> {
-            // define the caller...
+        // 🟢 52 ACCEPT ISSUED CLAIM - Accept a claim issued to the caller, adding it to
+        // their resume as though they had just made it
+        pub fn accept_issued_claim(&mut self, claim_id: Hash) -> Result<(), Error> {
             let caller = Self::env().caller();
-            // get the current set of claims for this account
-            let mut currentclaims = self.account_claims_workhistory.get(caller).unwrap_or_default();
+            let mut new_details = self.take_issued_claim(caller, claim_id)?;
+            new_details.created_at = Self::env().block_timestamp();
+            new_details.created_block = Self::env().block_number();
 
-            // if the data is too big send an error
-            if keywords_or_description.len() > 600 || url_link_to_see_more.len() > 600 {
-                return Err(Error::DataTooLarge)
-            }
-            else {
-                // set up the data that will go into the claim_hash
-                let claimant = Self::env().caller();
-                let claim_contents = keywords_or_description.clone();
-
-                // create the claim_hash by hashing the claimant and claim data
-                let encodable = (claimant, claim_contents); // Implements `scale::Encode`
-                let mut claim_hash_u8 = <Sha2x256 as HashOutput>::Type::default(); // 256-bit buffer
-                ink::env::hash_encoded::<Sha2x256, _>(&encodable, &mut claim_hash_u8);
-                let claim_hash: Hash = Hash::from(claim_hash_u8);
-
-                // Check to make sure the claim is not a duplicate
-                if self.claim_details.contains(claim_hash) {
-                    // if TRUE, issue an error
-                    return Err(Error::DuplicateClaim)
-                }
-                // if FALSE...set the contract storage for this claim...
-                
-                // add this claim to the claim_details map
-                let new_details = Details {
-                    claimtype: 1,
-                    claimant: Self::env().caller(),
-                    claim: keywords_or_description,
-                    claim_id: claim_hash,
-                    endorser_count: 0,
-                    link: url_link_to_see_more,
-                    show: true,
-                    endorsers: vec![Self::env().caller()]
-                };
+            // emit an event to register the acceptance to the chain
+            Self::env().emit_event(IssuedClaimAnswered {
+                subject: caller,
+                claim_id,
+                accepted: true,
+            });
 
-                if self.claim_details.try_insert(claim_hash, &new_details).is_err() {
-                    return Err(Error::DataTooLarge);
-                }
-                
-                // add this claim hash to the set of claims for this account
-                // if there are already 10 claims, kick out the oldest
-                if currentclaims.claims.len() > 9 {
-                    currentclaims.claims.remove(0);
-                }
-                // add the claim hash to the Claims.claims vector of claim_id hashes
-                currentclaims.claims.push(claim_hash);
-                // update the account_claims mapping
-                self.account_claims_workhistory.insert(caller, &currentclaims);
-
-                // add this event to the all_claims StorageVec
-                self.all_claims_work.push(&claim_hash);
-
-                // then emit an event to register the claim to the chain
-                // make a clone of claim_meta 
-                let claim_meta_clone = new_details.claim.clone();
-                Self::env().emit_event(ClaimMadeWorkHistory {
-                    claimant: Self::env().caller(),
-                    claim: claim_meta_clone,
-                    claim_id: claim_hash
-                });
+            self.store_claim(new_details)
+        }
 
-                // REWARD PROGRAM ACTIONS... update the claim_counter 
-                self.claim_counter = self.claim_counter.wrapping_add(1);
-                // IF conditions are met THEN payout a reward
-                let min = self.reward_amount.saturating_add(10);
-                let payout: Balance = self.reward_amount;
-                if self.reward_on == 1 && self.reward_balance > payout && self.env().balance() > min
-                && self.claim_counter.checked_rem_euclid(self.reward_interval) == Some(0) {
-                    // payout
-                    if self.env().transfer(caller, payout).is_err() {
-                        return Err(Error::PayoutFailed);
-                    }
-                    // update reward_balance
-                    self.reward_balance = self.reward_balance.saturating_sub(payout);
-                    // update reward_payouts
-                    self.reward_payouts = self.reward_payouts.saturating_add(payout);
-                    // emit an event to register the reward to the chain
-                    Self::env().emit_event(AccountRewardedLifeAndWork {
-                        claimant: caller,
-                        reward: payout
-                    });
-                }
-                // END REWARD PROGRAM ACTIONS
 
-            }
-            
+        #[ink(message)]
+        // 🟢 53 REJECT ISSUED CLAIM - Turn down a claim issued to the caller. The same 
+        // claim cannot be issued to the caller again.
+        pub fn reject_issued_claim(&mut self, claim_id: Hash) -> Result<(), Error> {
+            let caller = Self::env().caller();
+            self.take_issued_claim(caller, claim_id)?;
+            self.rejected_claims.insert(claim_id, &caller);
+
+            // emit an event to register the rejection to the chain
+            Self::env().emit_event(IssuedClaimAnswered {
+                subject: caller,
+                claim_id,
+                accepted: false,
+            });
+
             Ok(())
         }
 
 
         #[ink(message)]
-        // 🟢 2 EDUCATION - Updates the storage map and emits an event to register the claim on chain
-        pub fn make_claim_education(&mut self, 
+        // 🟢 0 EXPERTISE - Legacy wrapper for make_claim with ClaimType::Expertise
+        pub fn make_claim_expertise(&mut self, 
             keywords_or_description: Vec<u8>, url_link_to_see_more: Vec<u8>
         ) -> Result<(), Error> {
-            // define the caller...
-            let caller = Self::env().caller();
-            // get the current set of claims for this account
-            let mut currentclaims = self.account_claims_education.get(caller).unwrap_or_default();
+            self.make_claim(ClaimType::Expertise, keywords_or_description, url_link_to_see_more, None)
+        }
 
-            // if the data is too big send an error
-            if keywords_or_description.len() > 600 || url_link_to_see_more.len() > 600 {
-                return Err(Error::DataTooLarge)
-            }
-            else {
-                // set up the data that will go into the claim_hash
-                let claimant = Self::env().caller();
-                let claim_contents = keywords_or_description.clone();
-
-                // create the claim_hash by hashing the claimant and claim data
-                let encodable = (claimant, claim_contents); // Implements `scale::Encode`
-                let mut claim_hash_u8 = <Sha2x256 as HashOutput>::Type::default(); // 256-bit buffer
-                ink::env::hash_encoded::<Sha2x256, _>(&encodable, &mut claim_hash_u8);
-                let claim_hash: Hash = Hash::from(claim_hash_u8);
-
-                // Check to make sure the claim is not a duplicate
-                if self.claim_details.contains(claim_hash) {
-                    // if TRUE, issue an error
-                    return Err(Error::DuplicateClaim)
-                }
-                // if FALSE...set the contract storage for this claim...
-                
-                // add this claim to the claim_details map
-                let new_details = Details {
-                    claimtype: 2,
-                    claimant: Self::env().caller(),
-                    claim: keywords_or_description,
-                    claim_id: claim_hash,
-                    endorser_count: 0,
-                    link: url_link_to_see_more,
-                    show: true,
-                    endorsers: vec![Self::env().caller()]
-                };
-                
-                if self.claim_details.try_insert(claim_hash, &new_details).is_err() {
-                    return Err(Error::DataTooLarge);
-                }
-                
-                // add this claim hash to the set of claims for this account
-                // if there are already 20 claims, kick out the oldest
-                if currentclaims.claims.len() > 19 {
-                    currentclaims.claims.remove(0);
-                }
-                // add the claim hash to the Claims.claims vector of claim_id hashes
-                currentclaims.claims.push(claim_hash);
-                // update the account_claims mapping
-                self.account_claims_education.insert(caller, &currentclaims);
-
-                // add this event to the all_claims StorageVec
-                self.all_claims_education.push(&claim_hash);
-
-                // then emit an event to register the claim to the chain
-                // make a clone of claim_meta 
-                let claim_meta_clone = new_details.claim.clone();
-                Self::env().emit_event(ClaimMadeEducation {
-                    claimant: Self::env().caller(),
-                    claim: claim_meta_clone,
-                    claim_id: claim_hash
-                });
 
-                // REWARD PROGRAM ACTIONS... update the claim_counter 
-                self.claim_counter = self.claim_counter.wrapping_add(1);
-                // IF conditions are met THEN payout a reward
-                let min = self.reward_amount.saturating_add(10);
-                let payout: Balance = self.reward_amount;
-                if self.reward_on == 1 && self.reward_balance > payout && self.env().balance() > min
-                && self.claim_counter.checked_rem_euclid(self.reward_interval) == Some(0) {
-                    // payout
-                    if self.env().transfer(caller, payout).is_err() {
-                        return Err(Error::PayoutFailed);
-                    }
-                    // update reward_balance
-                    self.reward_balance = self.reward_balance.saturating_sub(payout);
-                    // update reward_payouts
-                    self.reward_payouts = self.reward_payouts.saturating_add(payout);
-                    // emit an event to register the reward to the chain
-                    Self::env().emit_event(AccountRewardedLifeAndWork {
-                        claimant: caller,
-                        reward: payout
-                    });
-                }
-                // END REWARD PROGRAM ACTIONS
-            }
-            
-            Ok(())
+        #[ink(message)]
+        // 🟢 1 WORK - Legacy wrapper for make_claim with ClaimType::WorkHistory
+        pub fn make_claim_workhistory(&mut self, 
+            keywords_or_description: Vec<u8>, url_link_to_see_more: Vec<u8>
+        ) -> Result<(), Error> {
+            self.make_claim(ClaimType::WorkHistory, keywords_or_description, url_link_to_see_more, None)
         }
 
 
         #[ink(message)]
-        // 🟢 3 GOOD DEEDS - Updates the storage map and emits an event to register the claim on chain
-        pub fn make_claim_gooddeed(&mut self, 
+        // 🟢 2 EDUCATION - Legacy wrapper for make_claim with ClaimType::Education
+        pub fn make_claim_education(&mut self, 
             keywords_or_description: Vec<u8>, url_link_to_see_more: Vec<u8>
         ) -> Result<(), Error> {
-            // define the caller...
-            let caller = Self::env().caller();
-            // get the current set of claims for this account
-            let mut currentclaims = self.account_claims_gooddeeds.get(caller).unwrap_or_default();
-
-            // if the data is too big send an error
-            if keywords_or_description.len() > 600 || url_link_to_see_more.len() > 600 {
-                return Err(Error::DataTooLarge)
-            }
-            else {
-                // set up the data that will go into the claim_hash
-                let claimant = Self::env().caller();
-                let claim_contents = keywords_or_description.clone();
-
-                // create the claim_hash by hashing the claimant and claim data
-                let encodable = (claimant, claim_contents); // Implements `scale::Encode`
-                let mut claim_hash_u8 = <Sha2x256 as HashOutput>::Type::default(); // 256-bit buffer
-                ink::env::hash_encoded::<Sha2x256, _>(&encodable, &mut claim_hash_u8);
-                let claim_hash: Hash = Hash::from(claim_hash_u8);
-
-                // Check to make sure the claim is not a duplicate
-                if self.claim_details.contains(claim_hash) {
-                    // if TRUE, issue an error
-                    return Err(Error::DuplicateClaim)
-                }
-                // if FALSE...set the contract storage for this claim...
-                
-                // add this claim to the claim_details map
-                let new_details = Details {
-                    claimtype: 4,
-                    claimant: Self::env().caller(),
-                    claim: keywords_or_description,
-                    claim_id: claim_hash,
-                    endorser_count: 0,
-                    link: url_link_to_see_more,
-                    show: true,
-                    endorsers: vec![Self::env().caller()]
-                };
-                
-                if self.claim_details.try_insert(claim_hash, &new_details).is_err() {
-                    return Err(Error::DataTooLarge);
-                }
+            self.make_claim(ClaimType::Education, keywords_or_description, url_link_to_see_more, None)
+        }
 
-                // add this claim hash to the set of claims for this account
-                // if there are already 20 claims, kick out the oldest
-                if currentclaims.claims.len() > 19 {
-                    currentclaims.claims.remove(0);
-                }
-                // add the claim hash to the Claims.claims vector of claim_id hashes
-                currentclaims.claims.push(claim_hash);
-                // update the account_claims mapping
-                self.account_claims_gooddeeds.insert(caller, &currentclaims);
-
-                // add this event to the all_claims StorageVec
-                self.all_claims_deeds.push(&claim_hash);
-
-                // then emit an event to register the claim to the chain
-                // make a clone of claim_meta 
-                let claim_meta_clone = new_details.claim.clone();
-                Self::env().emit_event(ClaimMadeGoodDeed {
-                    claimant: Self::env().caller(),
-                    claim: claim_meta_clone,
-                    claim_id: claim_hash
-                });
 
-                // REWARD PROGRAM ACTIONS... update the claim_counter 
-                self.claim_counter = self.claim_counter.wrapping_add(1);
-                // IF conditions are met THEN payout a reward
-                let min = self.reward_amount.saturating_add(10);
-                let payout: Balance = self.reward_amount;
-                if self.reward_on == 1 && self.reward_balance > payout && self.env().balance() > min
-                && self.claim_counter.checked_rem_euclid(self.reward_interval) == Some(0) {
-                    // payout
-                    if self.env().transfer(caller, payout).is_err() {
-                        return Err(Error::PayoutFailed);
-                    }
-                    // update reward_balance
-                    self.reward_balance = self.reward_balance.saturating_sub(payout);
-                    // update reward_payouts
-                    self.reward_payouts = self.reward_payouts.saturating_add(payout);
-                    // emit an event to register the reward to the chain
-                    Self::env().emit_event(AccountRewardedLifeAndWork {
-                        claimant: caller,
-                        reward: payout
-                    });
-                }
-                // END REWARD PROGRAM ACTIONS
-            }
-            
-            Ok(())
+        #[ink(message)]
+        // 🟢 3 GOOD DEEDS - Legacy wrapper for make_claim with ClaimType::GoodDeed
+        pub fn make_claim_gooddeed(&mut self, 
+            keywords_or_description: Vec<u8>, url_link_to_see_more: Vec<u8>
+        ) -> Result<(), Error> {
+            self.make_claim(ClaimType::GoodDeed, keywords_or_description, url_link_to_see_more, None)
         }
 
         #[ink(message)]
-        // 🟢 4 IP - Updates the storage map and emits an event to register the claim on chain
+        // 🟢 4 IP - Legacy wrapper for make_claim with ClaimType::IntellectualProperty.
+        // BREAKING: the claim_id is no longer the file hash, see CLAIM IDS at the top.
         pub fn make_claim_intellectualproperty(&mut self, 
             keywords_or_description: Vec<u8>, url_link_to_see_more: Vec<u8>, 
             hash_your_intellectual_property_file_here: Hash
         ) -> Result<(), Error> {
-            // define the caller...
-            let caller = Self::env().caller();
-            // get the current set of claims for this account
-            let mut currentclaims = self.account_claims_intellectualproperty.get(caller).unwrap_or_default();
-
-            // if the data is too big send an error
-            if keywords_or_description.len() > 600 || url_link_to_see_more.len() > 600 {
-                return Err(Error::DataTooLarge)
-            }
-            else {
-                let claim_hash = hash_your_intellectual_property_file_here;
-
-                // Check to make sure the claim is not a duplicate
-                if self.claim_details.contains(claim_hash) {
-                    // if TRUE, issue an error
-                    return Err(Error::DuplicateClaim)
-                }
-                // if FALSE...set the contract storage for this claim...
-                
-                // add this claim to the claim_details map
-                let new_details = Details {
-                    claimtype: 5,
-                    claimant: Self::env().caller(),
-                    claim: keywords_or_description,
-                    claim_id: claim_hash,
-                    endorser_count: 0,
-                    link: url_link_to_see_more,
-                    show: true,
-                    endorsers: vec![Self::env().caller()]
-                };
-                
-                if self.claim_details.try_insert(claim_hash, &new_details).is_err() {
-                    return Err(Error::DataTooLarge);
-                }
-
-                // add this claim hash to the set of claims for this account
-                // if there are already 20 claims, kick out the oldest
-                if currentclaims.claims.len() > 19 {
-                    currentclaims.claims.remove(0);
-                }
-                // add the claim hash to the Claims.claims vector of claim_id hashes
-                currentclaims.claims.push(claim_hash);
-                // update the account_claims mapping
-                self.account_claims_intellectualproperty.insert(caller, &currentclaims);
-
-                // add this event to the all_claims StorageVec
-                self.all_claims_ip.push(&claim_hash);
-
-                // then emit an event to register the claim to the chain
-                // make a clone of claim_meta 
-                let claim_meta_clone = new_details.claim.clone();
-                Self::env().emit_event(ClaimMadeIntellectualProperty {
-                    claimant: Self::env().caller(),
-                    claim: claim_meta_clone,
-                    claim_id: claim_hash
-                });
-
-                // REWARD PROGRAM ACTIONS... update the claim_counter 
-                self.claim_counter = self.claim_counter.wrapping_add(1);
-                // IF conditions are met THEN payout a reward
-                let min = self.reward_amount.saturating_add(10);
-                let payout: Balance = self.reward_amount;
-                if self.reward_on == 1 && self.reward_balance > payout && self.env().balance() > min
-                && self.claim_counter.checked_rem_euclid(self.reward_interval) == Some(0) {
-                    // payout
-                    if self.env().transfer(caller, payout).is_err() {
-                        return Err(Error::PayoutFailed);
-                    }
-                    // update reward_balance
-                    self.reward_balance = self.reward_balance.saturating_sub(payout);
-                    // update reward_payouts
-                    self.reward_payouts = self.reward_payouts.saturating_add(payout);
-                    // emit an event to register the reward to the chain
-                    Self::env().emit_event(AccountRewardedLifeAndWork {
-                        claimant: caller,
-                        reward: payout
-                    });
-                }
-                // END REWARD PROGRAM ACTIONS
-            }
-            
-            Ok(())
+            self.make_claim(ClaimType::IntellectualProperty, keywords_or_description, url_link_to_see_more, 
+                Some(hash_your_intellectual_property_file_here))
         }
 
-
         #[ink(message)]
         // 🟢 5 ENDORSE - Updates the storage map and emits an event to register the endorsement on chain 
         pub fn endorse_claim(&mut self, claim_id: Hash
@@ -767,11 +1306,15 @@ mod life_and_work {
                 let caller = Self::env().caller();
                 // Get the list of endorsers for this claimID from the claim_details
                 let mut current_details = self.claim_details.get(claim_id).unwrap_or_default();
-                // Is the caller is already in the endorsers list for this claim?... 
-                if current_details.endorsers.contains(&caller) || caller == current_details.claimant {
+                // Has the caller already endorsed this version of the claim?... 
+                if self.is_endorser(claim_id, &current_details, caller) || caller == current_details.claimant {
                     // If TRUE, return an Error... DuplicateEndorsement
                     return Err(Error::DuplicateEndorsement);
                 } 
+                // an endorsement the caller revoked is only given again through reendorse_claim
+                else if self.revoked_endorsements.contains((claim_id, caller)) {
+                    return Err(Error::RevokedEndorsement);
+                }
                 else {
                     // If the caller is NOT already an endorser...
                     // if there are more than 20 endorsers, kick out the oldest
@@ -781,34 +1324,34 @@ mod life_and_work {
 
                     // store the new endorser
                     current_details.endorsers.push(caller);
+                    // and flag it if it is a verified institution, kicking out the oldest after 20
+                    if self.is_verified_institution(caller) {
+                        if current_details.verified_endorsers.len() > 19 {
+                            current_details.verified_endorsers.remove(0);
+                        }
+                        current_details.verified_endorsers.push(caller);
+                    }
+                    // an endorser of an earlier version is now endorsing the current one
+                    current_details.earlier_endorsers.retain(|endorser| *endorser != caller);
 
-                    // update the endorser count
-                    let new_endorser_count = current_details.endorser_count.saturating_add(1);
+                    // update the endorser count and when the claim was last endorsed
+                    current_details.endorser_count = current_details.endorser_count.saturating_add(1);
+                    current_details.last_endorsed_at = Self::env().block_timestamp();
 
-                    // Update the details in storage for this claim
-                    let updated_details: Details = Details {
-                        claimtype: current_details.claimtype,
-                        claimant: current_details.claimant,
-                        claim: current_details.claim,
-                        claim_id: claim_id,
-                        endorser_count: new_endorser_count,
-                        link: current_details.link,
-                        show: current_details.show,
-                        endorsers: current_details.endorsers
-                    };
-
-                    // Update the claim_map
-                    if self.claim_details.try_insert(claim_id, &updated_details).is_err() {
+                    // Update the claim_map and record the endorsement in full
+                    if self.claim_details.try_insert(claim_id, &current_details).is_err() {
                         return Err(Error::DataTooLarge);
                     }
+                    self.endorsements.insert((claim_id, caller), &current_details.endorsement_round);
 
                     // emit an event to register the endorsement to the chain
                     // the event will register regardless of if we no longer have room
                     // for this endorsement in the contract storage
                     Self::env().emit_event(ClaimEndorsed {
                         claimant: current_details.claimant,
-                        claim_id: claim_id,
-                        endorser: Self::env().caller()
+                        claim_id,
+                        endorser: Self::env().caller(),
+                        claim_type: current_details.claimtype,
                     }); 
                 }
             }
@@ -821,31 +1364,72 @@ mod life_and_work {
         }
 
 
-        // 🟢 6 SHOW/HIDE - Show or hide a given claimID hash IF the caller is the owner
+        // 🟢 63 REVOKE ENDORSEMENT - Take back the caller's endorsement of a claim. The
+        // caller is removed from the endorsers, the endorser count goes down by one, and 
+        // the revocation is recorded so the caller cannot endorse the claim again by
+        // accident. Use reendorse_claim to endorse it again on purpose.
         #[ink(message)]
-        pub fn show_or_hide_claim(&mut self, claim_id: Hash, set_to_show: bool
-        ) -> Result<(), Error> {
-            
-            // first, get the details and make sure the caller owns this claimID
+        pub fn revoke_endorsement(&mut self, claim_id: Hash) -> Result<(), Error> {
             let caller = Self::env().caller();
-            let details = self.claim_details.get(claim_id).unwrap_or_default();
+            let mut details = match self.claim_details.get(claim_id) {
+                Some(details) => details,
+                None => return Err(Error::NonexistentClaim),
+            };
+            if caller == details.claimant || !self.is_endorser(claim_id, &details, caller) {
+                return Err(Error::NotEndorser)
+            }
 
-            if details.claimant == caller {
-                // set the show boolean to set_to_show
-                let updated_details: Details = Details {
-                    claimtype: details.claimtype,
-                    claimant: details.claimant,
-                    claim: details.claim,
-                    claim_id: claim_id,
-                    endorser_count: details.endorser_count,
-                    link: details.link,
-                    show: set_to_show,
-                    endorsers: details.endorsers
-                };
-                
-                // Update the claim_map
-                if self.claim_details.try_insert(claim_id, &updated_details).is_err() {
-                    return Err(Error::DataTooLarge);
+            // remove the endorsement
+            details.endorsers.retain(|endorser| *endorser != caller);
+            details.verified_endorsers.retain(|endorser| *endorser != caller);
+            details.endorser_count = details.endorser_count.saturating_sub(1);
+
+            // Update the claim_map and record the revocation
+            if self.claim_details.try_insert(claim_id, &details).is_err() {
+                return Err(Error::DataTooLarge);
+            }
+            self.endorsements.remove((claim_id, caller));
+            self.revoked_endorsements.insert((claim_id, caller), &Self::env().block_timestamp());
+
+            // emit an event to register the revocation to the chain
+            Self::env().emit_event(EndorsementRevoked {
+                claimant: details.claimant,
+                claim_id,
+                endorser: caller,
+                claim_type: details.claimtype,
+            });
+
+            Ok(())
+        }
+
+
+        // 🟢 64 REENDORSE - Endorse a claim again after revoking an earlier endorsement of it
+        #[ink(message)]
+        pub fn reendorse_claim(&mut self, claim_id: Hash) -> Result<(), Error> {
+            let caller = Self::env().caller();
+            if self.revoked_endorsements.take((claim_id, caller)).is_none() {
+                return Err(Error::NotEndorser)
+            }
+            self.endorse_claim(claim_id)
+        }
+
+
+        // 🟢 6 SHOW/HIDE - Show or hide a given claimID hash IF the caller is the owner
+        #[ink(message)]
+        pub fn show_or_hide_claim(&mut self, claim_id: Hash, set_to_show: bool
+        ) -> Result<(), Error> {
+            
+            // first, get the details and make sure the caller owns this claimID
+            let caller = Self::env().caller();
+            let mut details = self.claim_details.get(claim_id).unwrap_or_default();
+
+            if details.claimant == caller {
+                // set the show boolean to set_to_show
+                details.show = set_to_show;
+                
+                // Update the claim_map
+                if self.claim_details.try_insert(claim_id, &details).is_err() {
+                    return Err(Error::DataTooLarge);
                 }    
 
                 Ok(())
@@ -857,319 +1441,2991 @@ mod life_and_work {
         }
 
 
-        // MESSAGE FUNCTIONS THAT RETRIEVE DATA FROM STORAGE  >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>
+        // 🟢 18 AMEND - Replace the keywords and link of a claim IF the caller is the owner,
+        // keeping the same claimID and saving the previous version to the claim's revision history.
+        // If keep_endorsements is false, the current endorsers are moved to earlier_endorsers
+        // to show they endorsed an earlier version, and the endorser count starts over.
+        // If the keywords change, the co-authors of an IP claim have to confirm it again.
+        // Claims made with metadata are amended with amend_claim_metadata instead.
+        #[ink(message)]
+        pub fn amend_claim(&mut self, claim_id: Hash, 
+            new_keywords_or_description: Vec<u8>, new_url_link_to_see_more: Vec<u8>,
+            keep_endorsements: bool
+        ) -> Result<(), Error> {
+            self.amend(claim_id, new_keywords_or_description, new_url_link_to_see_more,
+                ClaimMetadata::None, keep_endorsements)
+        }
+
 
-        // 🟢 7 GET RESUME - Given an AccountID, return the detailed info for EVERY claim made by that account
+        // 🟢 32 AMEND METADATA - Replace the metadata and link of a claim made with metadata
+        // IF the caller is the owner, rebuilding its keywords from the new metadata. 
+        // Works the same way as amend_claim otherwise.
         #[ink(message)]
-        pub fn get_resume(&self, owner: AccountId) -> Vec<Details> {
-            // given the AccountID, get the set of each type of claimIDs
-            let idvec_work = self.account_claims_workhistory.get(owner).unwrap_or_default().claims;
-            let idvec_ed = self.account_claims_education.get(owner).unwrap_or_default().claims;
-            let idvec_expert = self.account_claims_expertise.get(owner).unwrap_or_default().claims;
-            let idvec_deeds = self.account_claims_gooddeeds.get(owner).unwrap_or_default().claims;
-            let idvec_ip = self.account_claims_intellectualproperty.get(owner).unwrap_or_default().claims;
-            let mut resume: Vec<Details> = Vec::new();
+        pub fn amend_claim_metadata(&mut self, claim_id: Hash, 
+            new_metadata: ClaimMetadata, new_url_link_to_see_more: Vec<u8>,
+            keep_endorsements: bool
+        ) -> Result<(), Error> {
+            if new_metadata == ClaimMetadata::None {
+                return Err(Error::InvalidMetadata)
+            }
+            let new_keywords = new_metadata.claim_keywords();
+            self.amend(claim_id, new_keywords, new_url_link_to_see_more, new_metadata, keep_endorsements)
+        }
 
-            // Iterate over each idvec: for each claimID...
 
-            for claimidhash in idvec_work.iter() {
-                // get the details
-                let resumeitem = self.claim_details.get(claimidhash).unwrap_or_default();
-                // then add that resume item to the resume vector
-                resume.push(resumeitem);
+        // 🟢 29 SET TENURE - Add, change or remove (with None) the tenure of a work history 
+        // or education claim IF the caller is the owner. The tenure of an issued claim is
+        // set by its issuer in issue_claim and cannot be changed.
+        #[ink(message)]
+        pub fn set_claim_tenure(&mut self, claim_id: Hash, tenure: Option<Tenure>) -> Result<(), Error> {
+            // first, get the details and make sure the caller owns this claimID
+            let caller = Self::env().caller();
+            let mut details = match self.claim_details.get(claim_id) {
+                Some(details) => details,
+                None => return Err(Error::NonexistentClaim),
+            };
+            if details.claimant != caller {
+                return Err(Error::CallerNotOwner)
+            }
+            if details.issuer.is_some() {
+                return Err(Error::PermissionDenied)
+            }
+            if let Some(tenure) = &tenure {
+                tenure.validate(details.claimtype)?;
             }
 
-            for claimidhash in idvec_ed.iter() {
-                // get the details
-                let resumeitem = self.claim_details.get(claimidhash).unwrap_or_default();
-                // then add that resume item to the resume vector
-                resume.push(resumeitem);
+            // Update the claim_map
+            details.tenure = tenure;
+            if self.claim_details.try_insert(claim_id, &details).is_err() {
+                return Err(Error::DataTooLarge);
             }
 
-            for claimidhash in idvec_expert.iter() {
-                // get the details
-                let resumeitem = self.claim_details.get(claimidhash).unwrap_or_default();
-                // then add that resume item to the resume vector
-                resume.push(resumeitem);
+            Ok(())
+        }
+
+
+        // 🟢 34 SET CO-AUTHORS - List the co-authors of an IP claim and their percentage 
+        // shares IF the caller is the owner. Co-authors who keep the same share stay 
+        // confirmed, everyone else has to confirm again with confirm_coauthorship.
+        // An empty list removes all co-authors.
+        #[ink(message)]
+        pub fn set_coauthors(&mut self, claim_id: Hash, 
+            coauthor_shares: Vec<(AccountId, u8)>
+        ) -> Result<(), Error> {
+            // first, get the details and make sure the caller owns this claimID
+            let caller = Self::env().caller();
+            let mut details = match self.claim_details.get(claim_id) {
+                Some(details) => details,
+                None => return Err(Error::NonexistentClaim),
+            };
+            if details.claimant != caller {
+                return Err(Error::CallerNotOwner)
+            }
+            if details.claimtype != ClaimType::IntellectualProperty {
+                return Err(Error::InvalidClaimType)
+            }
+            if coauthor_shares.len() > 10 {
+                return Err(Error::DataTooLarge)
             }
 
-            for claimidhash in idvec_deeds.iter() {
-                // get the details
-                let resumeitem = self.claim_details.get(claimidhash).unwrap_or_default();
-                // then add that resume item to the resume vector
-                resume.push(resumeitem);
+            // the shares must fit in 100 percent, without the claimant or any repeats
+            let mut total: u32 = 0;
+            for (position, (account, share)) in coauthor_shares.iter().enumerate() {
+                if *account == caller || coauthor_shares[..position].iter().any(|(earlier, _)| earlier == account) {
+                    return Err(Error::InvalidShares)
+                }
+                total = total.saturating_add(u32::from(*share));
             }
-            
-            for claimidhash in idvec_ip.iter() {
-                // get the details
-                let resumeitem = self.claim_details.get(claimidhash).unwrap_or_default();
-                // then add that resume item to the resume vector
-                resume.push(resumeitem);
+            if total > 100 {
+                return Err(Error::InvalidShares)
             }
-            
-            // Return the vector of ResumeItem structs
-            resume
 
-        }
+            // keep the confirmations of co-authors whose share has not changed
+            let mut coauthors: Vec<CoAuthor> = Vec::new();
+            for (account, share) in coauthor_shares.iter() {
+                let confirmed = details.coauthors.iter()
+                    .any(|c| c.account == *account && c.share == *share && c.confirmed);
+                if !confirmed {
+                    self.index_remove(IndexKey::CoAuthored(*account), claim_id);
+                }
+                coauthors.push(CoAuthor { account: *account, share: *share, confirmed });
+            }
+            // and drop the claim from the resumes of co-authors no longer listed
+            for old in details.coauthors.iter() {
+                if !coauthors.iter().any(|c| c.account == old.account) {
+                    self.index_remove(IndexKey::CoAuthored(old.account), claim_id);
+                }
+            }
 
+            // Update the claim_map
+            details.coauthors_confirmed = coauthors.iter().all(|c| c.confirmed);
+            details.coauthors = coauthors;
+            if self.claim_details.try_insert(claim_id, &details).is_err() {
+                return Err(Error::DataTooLarge);
+            }
 
-        // 🟢 8 Return the ENTIRE DETAILS struct for one claimID hash
-        #[ink(message)]
-        pub fn get_full_details(&self, claim_id: Hash) -> Details {
-            let details = self.claim_details.get(claim_id).unwrap_or_default();
-            details
+            // emit an event to register the co-authors to the chain
+            Self::env().emit_event(CoAuthorsSet {
+                claimant: caller,
+                claim_id,
+                coauthors: details.coauthors.iter().map(|c| c.account).collect(),
+            });
+
+            Ok(())
         }
 
-        // 🟢 9 GET ENDORSERS - for a given claim_id hash, get the 20 most recent ENDORSERS
+
+        // 🟢 35 CONFIRM CO-AUTHORSHIP - Countersign an IP claim that lists the caller as 
+        // a co-author. The claim then appears in the caller's resume too, and once every
+        // co-author has confirmed, the claim is fully confirmed.
         #[ink(message)]
-        pub fn get_endorsers(&self, claim_id: Hash) -> Vec<AccountId> {
-            let details = self.claim_details.get(claim_id).unwrap_or_default();
-            details.endorsers
+        pub fn confirm_coauthorship(&mut self, claim_id: Hash) -> Result<(), Error> {
+            let caller = Self::env().caller();
+            let mut details = match self.claim_details.get(claim_id) {
+                Some(details) => details,
+                None => return Err(Error::NonexistentClaim),
+            };
+            let Some(coauthor) = details.coauthors.iter_mut().find(|c| c.account == caller) else {
+                return Err(Error::PermissionDenied)
+            };
+            if coauthor.confirmed {
+                return Err(Error::DuplicateEndorsement)
+            }
+            coauthor.confirmed = true;
+            details.coauthors_confirmed = details.coauthors.iter().all(|c| c.confirmed);
+
+            // Update the claim_map and the caller's co-authored claims
+            if self.claim_details.try_insert(claim_id, &details).is_err() {
+                return Err(Error::DataTooLarge);
+            }
+            self.index_add(IndexKey::CoAuthored(caller), claim_id);
+
+            // emit an event to register the confirmation to the chain
+            Self::env().emit_event(CoAuthorshipConfirmed {
+                coauthor: caller,
+                claim_id,
+                fully_confirmed: details.coauthors_confirmed,
+            });
+
+            Ok(())
         }
 
-        /*  🟢 10 KEYWORD SEARCH ...
-        FOR A GIVEN KEYWORD OR KEY PHRASE, GET THE CLAIMS WHOSE CLAIM KEYWORDS
-        INCLUDE THAT ENTIRE WORD OR PHRASE.
-        Notes: You cannot iterate on a mapping BUT you can iterate on a VECTOR so we have 
-        an additional storage lines that looks like claim_hashes: Vec<Hash> where we 
-        keep a running vector of all the claim id hashes and iterate over that instead.
-        We have to convert the u8 vectors to strings so that we can use the contains()
-        function on the whole set of u8 items in the keywords rather than just one letter. 
-        */
+
+        // 🟢 36 SET LICENSE TERMS - Offer licenses to an IP claim on the given terms IF the
+        // caller is the owner, or stop offering them with None. Licenses already sold are kept.
         #[ink(message)]
-        pub fn get_matching_claims(&self, 
-            claim_type: u8,
-            keywords1: Vec<u8>, 
-            keywords2: Vec<u8>, 
-            keywords3: Vec<u8>) -> Vec<Details> {
-            // get a string for your keywords
-            let searchstring1 = String::from_utf8(keywords1).unwrap_or_default();
-            let searchstring2 = String::from_utf8(keywords2).unwrap_or_default();
-            let searchstring3 = String::from_utf8(keywords3).unwrap_or_default();
-            // set up your results vector
-            let mut matching_resume_items: Vec<Details> = Vec::new();
-
-            // iterate over the all_claims storage vectors to find claims that match all keywords...
-            if claim_type == 1 {
-                if self.all_claims_work.len() > 0 {
-                    for i in 0..self.all_claims_work.len() {
-                        let claimidhash = self.all_claims_work.get(i).unwrap_or_default();
-                        let resumeitem = self.claim_details.get(claimidhash).unwrap_or_default();
-                        let claimvecu8 = resumeitem.claim.clone();
-                        let claimstring = String::from_utf8(claimvecu8).unwrap_or_default();
-                        // if ALL the keywords are in the claim keyword set...
-                        if claimstring.contains(&searchstring1) && claimstring.contains(&searchstring2)
-                        && claimstring.contains(&searchstring3) {
-                            // add the details to the results vector
-                            matching_resume_items.push(resumeitem);
-                        }
-                    }
-                }
+        pub fn set_license_terms(&mut self, claim_id: Hash, 
+            license_terms: Option<LicenseTerms>
+        ) -> Result<(), Error> {
+            // first, get the details and make sure the caller owns this claimID
+            let caller = Self::env().caller();
+            let mut details = match self.claim_details.get(claim_id) {
+                Some(details) => details,
+                None => return Err(Error::NonexistentClaim),
+            };
+            if details.claimant != caller {
+                return Err(Error::CallerNotOwner)
             }
-
-            if claim_type == 2 {
-                if self.all_claims_education.len() > 0 {
-                    for i in 0..self.all_claims_education.len() {
-                        let claimidhash = self.all_claims_education.get(i).unwrap_or_default();
-                        let resumeitem = self.claim_details.get(claimidhash).unwrap_or_default();
-                        let claimvecu8 = resumeitem.claim.clone();
-                        let claimstring = String::from_utf8(claimvecu8).unwrap_or_default();
-                        // if ALL the keywords are in the claim keyword set...
-                        if claimstring.contains(&searchstring1) && claimstring.contains(&searchstring2)
-                        && claimstring.contains(&searchstring3) {
-                            // add the details to the results vector
-                            matching_resume_items.push(resumeitem);
-                        }
-                    }
+            if details.claimtype != ClaimType::IntellectualProperty {
+                return Err(Error::InvalidClaimType)
+            }
+            if let Some(terms) = &license_terms {
+                if terms.license_id.len() > 100 {
+                    return Err(Error::DataTooLarge)
                 }
             }
 
-            if claim_type == 3 {
-                if self.all_claims_expertise.len() > 0 {
-                    for i in 0..self.all_claims_expertise.len() {
-                        let claimidhash = self.all_claims_expertise.get(i).unwrap_or_default();
-                        let resumeitem = self.claim_details.get(claimidhash).unwrap_or_default();
-                        let claimvecu8 = resumeitem.claim.clone();
-                        let claimstring = String::from_utf8(claimvecu8).unwrap_or_default();
-                        // if ALL the keywords are in the claim keyword set...
-                        if claimstring.contains(&searchstring1) && claimstring.contains(&searchstring2)
-                        && claimstring.contains(&searchstring3) {
-                            // add the details to the results vector
-                            matching_resume_items.push(resumeitem);
-                        }
-                    }
-                }
+            // Update the claim_map
+            details.license_terms = license_terms;
+            if self.claim_details.try_insert(claim_id, &details).is_err() {
+                return Err(Error::DataTooLarge);
             }
 
-            if claim_type == 4 {
-                if self.all_claims_deeds.len() > 0 {
-                    for i in 0..self.all_claims_deeds.len() {
-                        let claimidhash = self.all_claims_deeds.get(i).unwrap_or_default();
-                        let resumeitem = self.claim_details.get(claimidhash).unwrap_or_default();
-                        let claimvecu8 = resumeitem.claim.clone();
-                        let claimstring = String::from_utf8(claimvecu8).unwrap_or_default();
-                        // if ALL the keywords are in the claim keyword set...
-                        if claimstring.contains(&searchstring1) && claimstring.contains(&searchstring2)
-                        && claimstring.contains(&searchstring3) {
-                            // add the details to the results vector
-                            matching_resume_items.push(resumeitem);
-                        }
-                    }
-                }
+            Ok(())
+        }
+
+
+        // 🟢 37 PURCHASE LICENSE - Buy a license to an IP claim on its current terms. The 
+        // payment is forwarded to the confirmed co-authors by their shares, and the rest 
+        // goes to the claimant. Paying more than the price is allowed, but not less.
+        #[ink(message, payable)]
+        pub fn purchase_license(&mut self, claim_id: Hash) -> Result<(), Error> {
+            let caller = Self::env().caller();
+            let payment: Balance = self.env().transferred_value();
+            let details = match self.visible_details(claim_id) {
+                Some(details) => details,
+                None => return Err(Error::NonexistentClaim),
+            };
+            let Some(terms) = details.license_terms else {
+                return Err(Error::NotLicensable)
+            };
+            if caller == details.claimant || self.licenses.contains((claim_id, caller)) {
+                return Err(Error::LicenseUnavailable)
+            }
+            if payment < terms.price {
+                return Err(Error::InsufficientPayment)
             }
 
-            if claim_type == 5 {
-                if self.all_claims_ip.len() > 0 {
-                    for i in 0..self.all_claims_ip.len() {
-                        let claimidhash = self.all_claims_ip.get(i).unwrap_or_default();
-                        let resumeitem = self.claim_details.get(claimidhash).unwrap_or_default();
-                        let claimvecu8 = resumeitem.claim.clone();
-                        let claimstring = String::from_utf8(claimvecu8).unwrap_or_default();
-                        // if ALL the keywords are in the claim keyword set...
-                        if claimstring.contains(&searchstring1) && claimstring.contains(&searchstring2)
-                        && claimstring.contains(&searchstring3) {
-                            // add the details to the results vector
-                            matching_resume_items.push(resumeitem);
-                        }
+            // record the license
+            let license = License {
+                claim_id,
+                licensee: caller,
+                license_id: terms.license_id.clone(),
+                price_paid: payment,
+                transferable: terms.transferable,
+                granted_at: Self::env().block_timestamp(),
+            };
+            self.licenses.insert((claim_id, caller), &license);
+            self.index_add(IndexKey::Licensed(caller), claim_id);
+
+            // pay each confirmed co-author their share, and the claimant the rest
+            let mut remainder = payment;
+            for coauthor in details.coauthors.iter().filter(|c| c.confirmed) {
+                let share = payment.saturating_mul(Balance::from(coauthor.share)) / 100;
+                if share > 0 {
+                    if self.env().transfer(coauthor.account, share).is_err() {
+                        return Err(Error::PayoutFailed);
                     }
+                    remainder = remainder.saturating_sub(share);
                 }
             }
+            if remainder > 0 && self.env().transfer(details.claimant, remainder).is_err() {
+                return Err(Error::PayoutFailed);
+            }
+
+            // emit an event to register the license to the chain
+            Self::env().emit_event(LicenseGranted {
+                claim_id,
+                licensee: caller,
+                license_id: terms.license_id,
+                price_paid: payment,
+            });
 
-            matching_resume_items
+            Ok(())
         }
 
 
-        // REWARD PROGRAM MESSAGES >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>
-        
-        // 🟢 11 Verify Account - returns the total number of claims in a given resume
+        // 🟢 38 TRANSFER LICENSE - Give the caller's license to an IP claim to another 
+        // account, if the license was bought on transferable terms
         #[ink(message)]
-        pub fn verify_account(&self, owner: AccountId) -> (u32, u32, u32, u32, u32) {
-            // given the AccountID, get the set of each type of claimIDs
-            let work = self.account_claims_workhistory.get(owner).unwrap_or_default().claims.len();
-            let ed = self.account_claims_education.get(owner).unwrap_or_default().claims.len();
-            let expert = self.account_claims_expertise.get(owner).unwrap_or_default().claims.len();
-            let deeds = self.account_claims_gooddeeds.get(owner).unwrap_or_default().claims.len();
-            let ip = self.account_claims_intellectualproperty.get(owner).unwrap_or_default().claims.len();
+        pub fn transfer_license(&mut self, claim_id: Hash, to: AccountId) -> Result<(), Error> {
+            let caller = Self::env().caller();
+            let mut license = match self.licenses.get((claim_id, caller)) {
+                Some(license) => license,
+                None => return Err(Error::NonexistentClaim),
+            };
+            if !license.transferable || to == caller || self.licenses.contains((claim_id, to)) {
+                return Err(Error::LicenseUnavailable)
+            }
+
+            // move the license to the new licensee
+            self.licenses.remove((claim_id, caller));
+            self.index_remove(IndexKey::Licensed(caller), claim_id);
+            license.licensee = to;
+            self.licenses.insert((claim_id, to), &license);
+            self.index_add(IndexKey::Licensed(to), claim_id);
 
-            let result = (work.try_into().unwrap(), ed.try_into().unwrap(), expert.try_into().unwrap(), deeds.try_into().unwrap(), ip.try_into().unwrap());
-            result
+            // emit an event to register the transfer to the chain
+            Self::env().emit_event(LicenseTransferred {
+                claim_id,
+                from: caller,
+                to,
+            });
+
+            Ok(())
         }
 
 
-        // 🟢 12 Rewards - Set Or Update Reward Root Account [RESTRICTED: ROOT]
+        // 🟢 41 OFFER IP TRANSFER - Offer to transfer ownership of an IP claim to another 
+        // account IF the caller is the owner. The transfer happens when that account 
+        // accepts it. A new offer replaces any earlier offer for the same claim.
         #[ink(message)]
-        pub fn set_reward_roots(&mut self, newroot: AccountId) -> Result<(), Error> {
+        pub fn offer_ip_transfer(&mut self, claim_id: Hash, to: AccountId) -> Result<(), Error> {
+            // first, get the details and make sure the caller owns this claimID
             let caller = Self::env().caller();
-            // if the root is already set, send an error
-            if self.reward_root_set != 1 || self.reward_root == caller {
-                // proceed - set the roots and update the storage
-                self.reward_root = newroot;
-                self.reward_root_set = 1;
+            let details = match self.claim_details.get(claim_id) {
+                Some(details) => details,
+                None => return Err(Error::NonexistentClaim),
+            };
+            if details.claimant != caller {
+                return Err(Error::CallerNotOwner)
             }
-            else {
-                // error PermissionDenied
+            if details.claimtype != ClaimType::IntellectualProperty {
+                return Err(Error::InvalidClaimType)
+            }
+            if to == caller {
                 return Err(Error::PermissionDenied)
             }
 
+            self.ip_transfer_offers.insert(claim_id, &to);
+
+            // emit an event to register the offer to the chain
+            Self::env().emit_event(IpTransferOffered {
+                claim_id,
+                from: caller,
+                to,
+            });
+
             Ok(())
         }
 
 
-        // 🟢 13 Rewards - Set/Update Reward Interval and Ammount [RESTRICTED: ROOT]
-        // Reward coin will be given to the account that makes the Xth claim in the system
+        // 🟢 42 CANCEL IP TRANSFER - Withdraw the offer to transfer an IP claim IF the 
+        // caller is the owner
         #[ink(message)]
-        pub fn set_reward(&mut self, on: u8, interval: u128, amount: Balance) -> Result<(), Error> {
+        pub fn cancel_ip_transfer(&mut self, claim_id: Hash) -> Result<(), Error> {
+            // first, get the details and make sure the caller owns this claimID
             let caller = Self::env().caller();
-            if self.reward_root == caller {
-                // proceed to set the reward program paramteters
-                self.reward_on = on;
-                self.reward_interval = interval;
-                self.reward_amount = amount;
-            }
-            else {
-                // error PermissionDenied
-                return Err(Error::PermissionDenied)
+            let details = match self.claim_details.get(claim_id) {
+                Some(details) => details,
+                None => return Err(Error::NonexistentClaim),
+            };
+            if details.claimant != caller {
+                return Err(Error::CallerNotOwner)
             }
-            
+            let Some(to) = self.ip_transfer_offers.take(claim_id) else {
+                return Err(Error::NonexistentClaim)
+            };
+
+            // emit an event to register the cancellation to the chain
+            Self::env().emit_event(IpTransferCancelled {
+                claim_id,
+                from: caller,
+                to,
+            });
+
             Ok(())
         }
 
-        // 🟢 14 ADD COIN TO REWARD ACCOUNT [RESTRICTED: ROOT]
-        #[ink(message, payable)]
-        pub fn add_reward_balance(&mut self) -> Result<(), Error> {
+
+        // 🟢 43 ACCEPT IP TRANSFER - Take ownership of an IP claim the caller was offered.
+        // The claim moves from the old owner's resume to the caller's, and the old owner
+        // is added to the claim's chain of previous owners. The caller takes the old owner's 
+        // place in the endorsers list and stops being an endorser of the claim.
+        #[ink(message)]
+        pub fn accept_ip_transfer(&mut self, claim_id: Hash) -> Result<(), Error> {
             let caller = Self::env().caller();
-            if self.reward_root == caller {
-                // add the paid in value to the reward_balance
-                let staked: Balance = self.env().transferred_value();
-                let newbalance: Balance = self.reward_balance.saturating_add(staked);
-                self.reward_balance = newbalance;
-            }
-            else {
-                // error PermissionDenied
+            if self.ip_transfer_offers.get(claim_id) != Some(caller) {
                 return Err(Error::PermissionDenied)
             }
-            
+            let mut details = match self.claim_details.get(claim_id) {
+                Some(details) => details,
+                None => return Err(Error::NonexistentClaim),
+            };
+            let claim_type = details.claimtype;
+            let from = details.claimant;
+            self.ip_transfer_offers.remove(claim_id);
+
+            // remove the claim from the old owner's resume
+            let mut oldclaims = self.get_account_claims(claim_type, from);
+            oldclaims.claims.retain(|id| *id != claim_id);
+            self.set_account_claims(claim_type, from, &oldclaims);
+
+            // and add it to the new owner's, kicking out the oldest if they are at the limit
+            let mut newclaims = self.get_account_claims(claim_type, caller);
+            if newclaims.claims.len() >= claim_type.max_account_claims() {
+                newclaims.claims.remove(0);
+            }
+            newclaims.claims.push(claim_id);
+            self.set_account_claims(claim_type, caller, &newclaims);
+
+            // the new owner cannot also be a co-author
+            if details.coauthors.iter().any(|c| c.account == caller) {
+                details.coauthors.retain(|c| c.account != caller);
+                details.coauthors_confirmed = details.coauthors.iter().all(|c| c.confirmed);
+                self.index_remove(IndexKey::CoAuthored(caller), claim_id);
+            }
+
+            // or an endorser of their own claim
+            if self.is_endorser(claim_id, &details, caller) {
+                details.endorsers.retain(|endorser| *endorser != caller);
+                details.verified_endorsers.retain(|endorser| *endorser != caller);
+                details.endorser_count = details.endorser_count.saturating_sub(1);
+                self.endorsements.remove((claim_id, caller));
+            }
+            // and the old owner's own place in the endorsers list passes to the new owner
+            if let Some(position) = details.endorsers.iter().position(|endorser| *endorser == from) {
+                details.endorsers[position] = caller;
+            }
+
+            // record the old owner in the provenance chain, kicking out the oldest after 20
+            if details.previous_owners.len() > 19 {
+                details.previous_owners.remove(0);
+            }
+            details.previous_owners.push(from);
+            details.claimant = caller;
+
+            // Update the claim_map
+            if self.claim_details.try_insert(claim_id, &details).is_err() {
+                return Err(Error::DataTooLarge);
+            }
+
+            // emit an event to register the transfer to the chain
+            Self::env().emit_event(IpTransferAccepted {
+                claim_id,
+                from,
+                to: caller,
+            });
+
             Ok(())
         }
 
 
-        // 🟢 15 RETREIVE COIN FROM REWARD ACCOUNT [RESTRICTED: ROOT]
-        // turns reward program off and returns funds to the root
+        // 🟢 45 SET BENEFICIARY - Name the account that benefited from a good deed, or 
+        // remove it with None, IF the caller is the owner. Any earlier confirmation is
+        // cleared, so the beneficiary has to confirm the deed again.
         #[ink(message)]
-        pub fn shut_down_reward(&mut self) -> Result<(), Error> {
+        pub fn set_beneficiary(&mut self, claim_id: Hash, beneficiary: Option<AccountId>) -> Result<(), Error> {
+            // first, get the details and make sure the caller owns this claimID
             let caller = Self::env().caller();
-            if self.reward_root == caller {
-                // set the reward program to off
-                self.reward_on = 0;
-                // refund the coin to the reward root
-                // Check that there is a nonzero balance on the contract > existential deposit
-                if self.env().balance() > 10 && self.reward_balance > 0 {
-                    // pay the root the reward_balance minus 10
-                    let payout: Balance = self.reward_balance.saturating_sub(10);
-                    if self.env().transfer(caller, payout).is_err() {
-                        return Err(Error::PayoutFailed);
-                    }
-                }
-                // if the balance is < 10, Error (ZeroBalance)
-                else {
-                    return Err(Error::ZeroBalance);
-                }
+            let mut details = match self.claim_details.get(claim_id) {
+                Some(details) => details,
+                None => return Err(Error::NonexistentClaim),
+            };
+            if details.claimant != caller {
+                return Err(Error::CallerNotOwner)
             }
-            else {
-                // error PermissionDenied
+            if details.claimtype != ClaimType::GoodDeed {
+                return Err(Error::InvalidClaimType)
+            }
+            if beneficiary == Some(caller) {
                 return Err(Error::PermissionDenied)
             }
-            
+
+            // move the claim to the new beneficiary's list of good deeds
+            if let Some(old) = details.beneficiary {
+                self.index_remove(IndexKey::Beneficiary(old), claim_id);
+            }
+            if let Some(new) = beneficiary {
+                self.index_add(IndexKey::Beneficiary(new), claim_id);
+            }
+
+            // Update the claim_map
+            details.beneficiary = beneficiary;
+            details.deed_confirmed = false;
+            if self.claim_details.try_insert(claim_id, &details).is_err() {
+                return Err(Error::DataTooLarge);
+            }
+
             Ok(())
         }
 
 
-        // 🟢 16 GET CURRENT REWARD BALANCE AND SETTINGS [RESTRICTED: ROOT]
+        // 🟢 46 CONFIRM GOOD DEED - Confirm a good deed that names the caller as its 
+        // beneficiary. This is kept apart from endorsements, which anyone can make.
         #[ink(message)]
-        pub fn get_reward_settings(&self) -> RewardSettings {
+        pub fn confirm_good_deed(&mut self, claim_id: Hash) -> Result<(), Error> {
             let caller = Self::env().caller();
-            let mut results = RewardSettings::default();
-            if self.reward_root == caller {
-                let settings = RewardSettings {
-                    reward_on: self.reward_on,
-                    reward_root_set: self.reward_root_set,
-                    reward_root: self.reward_root,
-                    reward_interval: self.reward_interval,
-                    reward_amount: self.reward_amount,
-                    reward_balance: self.reward_balance,
-                    reward_payouts: self.reward_payouts,
-                    claim_counter: self.claim_counter,
-                };
-                results = settings;
+            let mut details = match self.claim_details.get(claim_id) {
+                Some(details) => details,
+                None => return Err(Error::NonexistentClaim),
+            };
+            if details.beneficiary != Some(caller) {
+                return Err(Error::PermissionDenied)
+            }
+            if details.deed_confirmed {
+                return Err(Error::DuplicateEndorsement)
             }
 
-            results
-        }
+            // Update the claim_map
+            details.deed_confirmed = true;
+            if self.claim_details.try_insert(claim_id, &details).is_err() {
+                return Err(Error::DataTooLarge);
+            }
 
+            // emit an event to register the confirmation to the chain
+            Self::env().emit_event(GoodDeedConfirmed {
+                beneficiary: caller,
+                claim_id,
+            });
 
+            Ok(())
+        }
 
 
-    }
-    // END OF CONTRACT LOGIC
+        // 🟢 48 SET DEED IMPACT - Record the hours and quantity of a good deed, or remove
+        // them with None, IF the caller is the owner. The beneficiary has to confirm 
+        // the deed again before the new numbers count towards any impact totals.
+        #[ink(message)]
+        pub fn set_deed_impact(&mut self, claim_id: Hash, impact: Option<DeedImpact>) -> Result<(), Error> {
+            // first, get the details and make sure the caller owns this claimID
+            let caller = Self::env().caller();
+            let mut details = match self.claim_details.get(claim_id) {
+                Some(details) => details,
+                None => return Err(Error::NonexistentClaim),
+            };
+            if details.claimant != caller {
+                return Err(Error::CallerNotOwner)
+            }
+            if details.claimtype != ClaimType::GoodDeed {
+                return Err(Error::InvalidClaimType)
+            }
+
+            // Update the claim_map
+            if details.impact != impact {
+                details.impact = impact;
+                details.deed_confirmed = false;
+            }
+            if self.claim_details.try_insert(claim_id, &details).is_err() {
+                return Err(Error::DataTooLarge);
+            }
+
+            Ok(())
+        }
+
+
+        // 🟢 20 RETRACT - Permanently delete a claim IF the caller is the owner. The claim is
+        // removed from the account's resume and the search vectors, its details and revision
+        // history are deleted, and a tombstone is kept so the same claim cannot be made again.
+        // Licenses already sold for an IP claim are kept on purpose, as the licensees paid
+        // for them, so get_licenses still returns them after the claim is gone.
+        #[ink(message)]
+        pub fn retract_claim(&mut self, claim_id: Hash) -> Result<(), Error> {
+
+            // first, get the details and make sure the caller owns this claimID
+            let caller = Self::env().caller();
+            let details = match self.claim_details.get(claim_id) {
+                Some(details) => details,
+                None => return Err(Error::NonexistentClaim),
+            };
+            if details.claimant != caller {
+                return Err(Error::CallerNotOwner)
+            }
+            let claim_type = details.claimtype;
+
+            // remove the claim from the account's resume
+            let mut currentclaims = self.get_account_claims(claim_type, caller);
+            currentclaims.claims.retain(|id| *id != claim_id);
+            self.set_account_claims(claim_type, caller, &currentclaims);
+
+            // remove the claim from the all_claims StorageVec for this type
+            self.remove_all_claims(claim_type, claim_id);
+
+            // remove the claim from the keyword index and the normalized search text
+            self.unindex_keywords(claim_type, claim_id, &details.claim);
+            self.normalized_claims.remove(claim_id);
+
+            // remove the claim from the resumes of its co-authors
+            for coauthor in details.coauthors.iter() {
+                self.index_remove(IndexKey::CoAuthored(coauthor.account), claim_id);
+            }
+
+            // remove the claim from its volunteer's and beneficiary's lists of good deeds
+            self.index_remove(IndexKey::Volunteer(caller), claim_id);
+            if let Some(beneficiary) = details.beneficiary {
+                self.index_remove(IndexKey::Beneficiary(beneficiary), claim_id);
+            }
+
+            // withdraw any offer to transfer the claim
+            self.ip_transfer_offers.remove(claim_id);
+
+            // release the file hash of an IP claim
+            if let Some(file_hash) = details.file_hash {
+                if self.ip_file_hashes.get(file_hash) == Some(claim_id) {
+                    self.ip_file_hashes.remove(file_hash);
+                }
+            }
+
+            // delete the details and the revision history
+            self.claim_details.remove(claim_id);
+            for revision in 0..details.revision {
+                self.claim_revisions.remove((claim_id, revision));
+            }
+
+            // keep a tombstone so the claim cannot be silently re-created
+            self.retracted_claims.insert(claim_id, &caller);
+
+            // emit an event to register the retraction to the chain
+            Self::env().emit_event(ClaimRetracted {
+                claimant: caller,
+                claim_id,
+                claim_type,
+            });
+
+            Ok(())
+        }
+
+
+        // MESSAGE FUNCTIONS THAT RETRIEVE DATA FROM STORAGE  >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>
+
+        // 🟢 7 GET RESUME - Given an AccountID, return the detailed info for EVERY visible claim 
+        // made by that account, and the IP claims it has confirmed it co-authored. 
+        // Hidden claims are left out, see get_my_resume.
+        #[ink(message)]
+        pub fn get_resume(&self, owner: AccountId) -> Vec<Details> {
+            self.resume_items(owner, false)
+        }
+
+
+        // 🟢 22 GET MY RESUME - return the detailed info for EVERY claim made by the caller,
+        // including the claims the caller has hidden
+        #[ink(message)]
+        pub fn get_my_resume(&self) -> Vec<Details> {
+            self.resume_items(Self::env().caller(), true)
+        }
+
+
+        // 🟢 61 GET VERIFIED RESUME - Given an AccountID, return the same claims as get_resume
+        // with the verification level of each, leaving out claims below min_level
+        #[ink(message)]
+        pub fn get_verified_resume(&self, owner: AccountId, 
+            min_level: Option<VerificationLevel>
+        ) -> Vec<VerifiedDetails> {
+            self.resume_items(owner, false).into_iter()
+                .map(|details| VerifiedDetails { level: details.verification_level(), details })
+                .filter(|item| min_level.as_ref().is_none_or(|min| item.level.meets(min)))
+                .collect()
+        }
+
+
+        // 🟢 62 GET VERIFIED DETAILS - for one claimID hash, return its details and 
+        // verification level. A hidden claim is returned only to its owner.
+        #[ink(message)]
+        pub fn get_verified_details(&self, claim_id: Hash) -> Result<VerifiedDetails, Error> {
+            match self.visible_details(claim_id) {
+                Some(details) => Ok(VerifiedDetails { level: details.verification_level(), details }),
+                None => Err(Error::NonexistentClaim),
+            }
+        }
+
+
+        // 🟢 8 Return the ENTIRE DETAILS struct for one claimID hash. 
+        // A hidden claim is returned only to its owner.
+        #[ink(message)]
+        pub fn get_full_details(&self, claim_id: Hash) -> Details {
+            self.visible_details(claim_id).unwrap_or_default()
+        }
+
+        // 🟢 9 GET ENDORSERS - for a given claim_id hash, get the 20 most recent ENDORSERS
+        #[ink(message)]
+        pub fn get_endorsers(&self, claim_id: Hash) -> Vec<AccountId> {
+            let details = self.visible_details(claim_id).unwrap_or_default();
+            details.endorsers
+        }
+
+        /*  🟢 10 KEYWORD SEARCH ...
+        FOR A GIVEN KEYWORD OR KEY PHRASE, GET THE CLAIMS WHOSE CLAIM KEYWORDS
+        INCLUDE THAT ENTIRE WORD OR PHRASE.
+        Notes: You cannot iterate on a mapping BUT you can iterate on a VECTOR so we have 
+        an additional storage lines that looks like claim_hashes: Vec<Hash> where we 
+        keep a running vector of all the claim id hashes and iterate over that instead.
+        We have to convert the u8 vectors to strings so that we can use the contains()
+        function on the whole set of u8 items in the keywords rather than just one letter. 
+        */
+        #[ink(message)]
+        pub fn get_matching_claims(&self, 
+            claim_type: ClaimType,
+            keywords1: Vec<u8>, 
+            keywords2: Vec<u8>, 
+            keywords3: Vec<u8>) -> Result<Vec<Details>, Error> {
+            // Unknown is not a searchable claim type
+            if claim_type == ClaimType::Unknown {
+                return Err(Error::InvalidClaimType)
+            }
+            // get a string for your keywords
+            let searchstrings = vec![
+                String::from_utf8(keywords1).unwrap_or_default(),
+                String::from_utf8(keywords2).unwrap_or_default(),
+                String::from_utf8(keywords3).unwrap_or_default(),
+            ];
+
+            // scan the whole all_claims storage vector for this type to find claims that match all keywords...
+            let page = self.scan_claims(claim_type, 0, self.all_claims_len(claim_type), u32::MAX,
+                &SearchOptions::default(), |details| Self::matches_all_keywords(&details.claim, &searchstrings));
+
+            Ok(page.results)
+        }
+
+
+        /*  🟢 23 PAGED KEYWORD SEARCH ...
+        Same as the KEYWORD SEARCH above but for any number of keywords, looking at no more
+        than max_scan claims starting from start_index and returning no more than max_results
+        matches, so that the whole registry can be searched in a series of bounded calls.
+        Both limits are capped at MAX_SEARCH_SCAN and MAX_SEARCH_RESULTS. In the SearchOptions, 
+        use MatchMode::Normalized to ignore case, punctuation and extra whitespace or 
+        MatchMode::Exact to match as given, pick the SortOrder of the results, and set 
+        min_endorsements to filter out claims with too few endorsers.
+        Start with start_index 0 and pass the next_index of each page to continue. The 
+        index is a position in the search vector, so new claims do not shift it, and with 
+        SortOrder::NewestFirst the search walks down from just below it. 
+        With SortOrder::MostEndorsed the search looks at all max_scan claims rather than 
+        stopping once it has max_results matches, and returns the most endorsed of them.
+        */
+        #[ink(message)]
+        pub fn get_matching_claims_paged(&self, 
+            claim_type: ClaimType,
+            keywords: Vec<Vec<u8>>,
+            options: SearchOptions,
+            start_index: u32,
+            max_scan: u32,
+            max_results: u32) -> Result<SearchPage, Error> {
+            // Unknown is not a searchable claim type
+            if claim_type == ClaimType::Unknown {
+                return Err(Error::InvalidClaimType)
+            }
+            // if the search is too big send an error
+            if keywords.len() > MAX_SEARCH_KEYWORDS || keywords.iter().any(|keyword| keyword.len() > 600) {
+                return Err(Error::DataTooLarge)
+            }
+            // get a string for your keywords
+            let searchstrings: Vec<String> = keywords.into_iter()
+                .map(|keyword| match options.mode {
+                    MatchMode::Exact => String::from_utf8(keyword).unwrap_or_default(),
+                    MatchMode::Normalized => normalize_text(&keyword),
+                })
+                .collect();
+
+            // keep the scan and the results within bounds, and always make progress
+            let max_scan = max_scan.clamp(1, MAX_SEARCH_SCAN);
+            let max_results = max_results.clamp(1, MAX_SEARCH_RESULTS);
+
+            Ok(self.scan_claims(claim_type, start_index, max_scan, max_results, &options,
+                |details| self.claim_matches(options.mode, details, &searchstrings)))
+        }
+
+
+        /*  🟢 24 INDEXED KEYWORD SEARCH ...
+        Look up the claims of a type that include a keyword using the keyword index, 
+        so the cost depends on the number of matches rather than the size of the registry.
+        Keywords are matched as whole words, ignoring case. If the keyword is made of 
+        several words, claims must include all of them. Every keyword of every claim is 
+        in the index, because claims with more than 64 distinct keywords are rejected 
+        with DataTooLarge when they are made or amended. Page through the results with 
+        start_index and next_index, and sort and filter them with the SearchOptions, 
+        as in the PAGED KEYWORD SEARCH. The index is always searched as MatchMode::Normalized.
+        Retracting or amending a claim moves another claim into its place in the keyword
+        index, so a search continued with next_index after that may miss or repeat a claim.
+        */
+        #[ink(message)]
+        pub fn search_by_keyword(&self, 
+            claim_type: ClaimType,
+            keyword: Vec<u8>,
+            options: SearchOptions,
+            start_index: u32,
+            max_results: u32) -> Result<SearchPage, Error> {
+            // Unknown is not a searchable claim type
+            if claim_type == ClaimType::Unknown {
+                return Err(Error::InvalidClaimType)
+            }
+            // if the search is too big send an error
+            if keyword.len() > 600 {
+                return Err(Error::DataTooLarge)
+            }
+            let words = keyword_tokens(&keyword);
+            let max_results = max_results.clamp(1, MAX_SEARCH_RESULTS);
+
+            // walk the shortest index list among the words and check the claims for the others
+            let shortest = words.iter()
+                .map(|word| IndexKey::Keyword(claim_type, keyword_hash(word)))
+                .min_by_key(|key| self.index_len.get(key).unwrap_or(0));
+            let Some(key) = shortest else {
+                return Ok(SearchPage::default())
+            };
+            Ok(self.scan_list(ClaimList::Index(key), start_index, MAX_SEARCH_SCAN, max_results, &options,
+                |details| {
+                    let claim_words = keyword_tokens(&details.claim);
+                    words.iter().all(|word| claim_words.contains(word))
+                }))
+        }
+
+
+        /*  🟢 25 QUERY SEARCH ...
+        Search the claims of a type with a boolean query such as 
+            Rust AND (Substrate OR ink) NOT intern
+        With MatchMode::Normalized, terms are matched as whole words ignoring case and 
+        punctuation, so ink does not match thinking, and a "quoted phrase" matches its 
+        words next to each other in order. With MatchMode::Exact, terms and phrases are 
+        matched as raw text anywhere in the claim. AND, OR, NOT and parentheses combine 
+        them. AND binds tighter than OR, and terms
+        next to each other without an operator are ANDed, so the query above finds claims 
+        with Rust, with Substrate or ink, and without intern. The operators must be in 
+        capitals; in lowercase they are ordinary terms. Page through, sort and filter the 
+        results as in the PAGED KEYWORD SEARCH.
+        */
+        #[ink(message)]
+        pub fn query_claims(&self, 
+            claim_type: ClaimType,
+            query: Vec<u8>,
+            options: SearchOptions,
+            start_index: u32,
+            max_scan: u32,
+            max_results: u32) -> Result<SearchPage, Error> {
+            // Unknown is not a searchable claim type
+            if claim_type == ClaimType::Unknown {
+                return Err(Error::InvalidClaimType)
+            }
+            // if the query is too big send an error
+            if query.len() > 600 {
+                return Err(Error::DataTooLarge)
+            }
+            let query = parse_query(&query, options.mode)?;
+
+            // keep the scan and the results within bounds, and always make progress
+            let max_scan = max_scan.clamp(1, MAX_SEARCH_SCAN);
+            let max_results = max_results.clamp(1, MAX_SEARCH_RESULTS);
+
+            Ok(self.scan_claims(claim_type, start_index, max_scan, max_results, &options,
+                |details| query.matches(&self.claim_text(options.mode, details))))
+        }
+
+
+        /*  🟢 26 CROSS TYPE QUERY SEARCH ...
+        Search several claim types at once with a boolean query as in the QUERY SEARCH. 
+        Pass the claim types to search in the order to search them, or an empty vector 
+        to search every type. Start with start as None, then pass the next cursor from 
+        each page to continue. max_scan and max_results apply to the whole call, across 
+        all the types it looks at. The SearchOptions filters apply within each type, and 
+        the results of all the types in the page are then put in the SortOrder together.
+        With SortOrder::MostEndorsed every type is scanned until max_scan runs out, and 
+        the page holds the most endorsed max_results matches of all of them.
+        */
+        #[ink(message)]
+        pub fn query_claims_all_types(&self, 
+            claim_types: Vec<ClaimType>,
+            query: Vec<u8>,
+            options: SearchOptions,
+            start: Option<SearchCursor>,
+            max_scan: u32,
+            max_results: u32) -> Result<MultiSearchPage, Error> {
+            // an empty set of claim types means every type, and Unknown is not searchable
+            let mut search_types: Vec<ClaimType> = Vec::new();
+            for claim_type in claim_types {
+                if claim_type == ClaimType::Unknown {
+                    return Err(Error::InvalidClaimType)
+                }
+                if !search_types.contains(&claim_type) {
+                    search_types.push(claim_type);
+                }
+            }
+            if search_types.is_empty() {
+                search_types = ClaimType::ALL.to_vec();
+            }
+            // if the query is too big send an error
+            if query.len() > 600 {
+                return Err(Error::DataTooLarge)
+            }
+            let query = parse_query(&query, options.mode)?;
+
+            // find where to start, which must be one of the types being searched
+            let start = start.unwrap_or(SearchCursor { claim_type: search_types[0], index: 0 });
+            let Some(first) = search_types.iter().position(|claim_type| *claim_type == start.claim_type) else {
+                return Err(Error::InvalidClaimType)
+            };
+
+            // keep the scan and the results within bounds, and always make progress
+            let mut scan_left = max_scan.clamp(1, MAX_SEARCH_SCAN);
+            let max_results = max_results.clamp(1, MAX_SEARCH_RESULTS);
+            let mut page = MultiSearchPage::default();
+            let mut index = start.index;
+
+            for claim_type in search_types.iter().skip(first) {
+                // the most endorsed matches can be anywhere, so MostEndorsed fills the scan instead
+                let results_left = if options.sort == SortOrder::MostEndorsed {
+                    max_results
+                }
+                else {
+                    max_results.saturating_sub(page.results.len() as u32)
+                };
+                if scan_left == 0 || results_left == 0 {
+                    // out of room in this call, so pick up at the start of this type next time
+                    page.next = Some(SearchCursor { claim_type: *claim_type, index });
+                    break;
+                }
+                let type_page = self.scan_claims(*claim_type, index, scan_left, results_left, &options,
+                    |details| query.matches(&self.claim_text(options.mode, details)));
+                page.results.extend(type_page.results);
+                if let Some(next_index) = type_page.next_index {
+                    // this type is not finished
+                    page.next = Some(SearchCursor { claim_type: *claim_type, index: next_index });
+                    break;
+                }
+                // this type is finished, so count what was scanned and move on to the next
+                let (low, high) = self.scan_bounds(ClaimList::AllClaims(*claim_type), index, options.sort);
+                scan_left = scan_left.saturating_sub(high.saturating_sub(low));
+                index = 0;
+            }
+
+            options.rank(&mut page.results);
+            page.results.truncate(max_results as usize);
+            Ok(page)
+        }
+
+
+        // 🟢 27 COMPUTE CLAIM ID - the claim_id make_claim will give a claim of this type 
+        // with these keywords made by this claimant
+        #[ink(message)]
+        pub fn compute_claim_id(&self, claimant: AccountId, claim_type: ClaimType, 
+            keywords_or_description: Vec<u8>
+        ) -> Result<Hash, Error> {
+            // Unknown claims cannot be made
+            if claim_type == ClaimType::Unknown {
+                return Err(Error::InvalidClaimType)
+            }
+            Ok(Self::hash_claim(claimant, claim_type, &keywords_or_description))
+        }
+
+
+        // 🟢 30 GET TIMELINE - Given an AccountID, return its visible work history or 
+        // education claims that have a tenure in order of start date, with the gaps 
+        // of more than a day when none of them was current
+        #[ink(message)]
+        pub fn get_timeline(&self, owner: AccountId, claim_type: ClaimType) -> Result<Timeline, Error> {
+            if claim_type != ClaimType::WorkHistory && claim_type != ClaimType::Education {
+                return Err(Error::InvalidClaimType)
+            }
+            let mut timeline = Timeline::default();
+            for claimidhash in self.get_account_claims(claim_type, owner).claims.iter() {
+                let resumeitem = self.claim_details.get(claimidhash).unwrap_or_default();
+                if resumeitem.show && resumeitem.tenure.is_some() {
+                    timeline.entries.push(resumeitem);
+                }
+            }
+            timeline.entries.sort_by_key(|details| details.tenure.as_ref().map(|tenure| tenure.start_date));
+
+            // walk the entries keeping track of the latest end date so far. Once an 
+            // entry is current, every later start date is covered.
+            let mut covered_until: Option<(u32, i64)> = None;
+            let mut current = false;
+            for tenure in timeline.entries.iter().filter_map(|details| details.tenure.as_ref()) {
+                let start = day_number(tenure.start_date).unwrap_or_default();
+                if let (false, Some((until_date, until))) = (current, covered_until) {
+                    if start > until.saturating_add(1) {
+                        timeline.gaps.push(TimelineGap { after: until_date, before: tenure.start_date });
+                    }
+                }
+                match tenure.end_date {
+                    None => current = true,
+                    Some(end_date) => {
+                        let end = day_number(end_date).unwrap_or_default();
+                        if covered_until.is_none_or(|(_, until)| end > until) {
+                            covered_until = Some((end_date, end));
+                        }
+                    }
+                }
+            }
+            Ok(timeline)
+        }
+
+
+        // 🟢 33 GET IP CLAIM BY FILE HASH - for the hash of an IP file, get the IP claim 
+        // that registered it first. A hidden claim is returned only to its owner.
+        #[ink(message)]
+        pub fn get_ip_claim_by_file_hash(&self, file_hash: Hash) -> Result<Details, Error> {
+            match self.file_hash_claim(file_hash) {
+                Some(details) if details.show || details.claimant == Self::env().caller() => Ok(details),
+                _ => Err(Error::NonexistentClaim),
+            }
+        }
+
+
+        // 🟢 39 GET LICENSES - Given an AccountID, return every IP license it holds, 
+        // including licenses to IP claims that have since been retracted
+        #[ink(message)]
+        pub fn get_licenses(&self, licensee: AccountId) -> Vec<License> {
+            let mut licenses: Vec<License> = Vec::new();
+            for claim_id in self.index_claims(IndexKey::Licensed(licensee)) {
+                if let Some(license) = self.licenses.get((claim_id, licensee)) {
+                    licenses.push(license);
+                }
+            }
+            licenses
+        }
+
+
+        // 🟢 40 HAS LICENSE - does this account hold a license to this IP claim?
+        #[ink(message)]
+        pub fn has_license(&self, claim_id: Hash, licensee: AccountId) -> bool {
+            self.licenses.contains((claim_id, licensee))
+        }
+
+
+        // 🟢 44 GET IP TRANSFER OFFER - for a given claim_id hash, the account it has been
+        // offered to, if there is an open offer
+        #[ink(message)]
+        pub fn get_ip_transfer_offer(&self, claim_id: Hash) -> Option<AccountId> {
+            self.ip_transfer_offers.get(claim_id)
+        }
+
+
+        // 🟢 47 GET GOOD DEEDS FOR - Given an AccountID, return the visible good deeds 
+        // that name it as their beneficiary, confirmed or not
+        #[ink(message)]
+        pub fn get_good_deeds_for(&self, beneficiary: AccountId) -> Vec<Details> {
+            let mut deeds: Vec<Details> = Vec::new();
+            for claim_id in self.index_claims(IndexKey::Beneficiary(beneficiary)) {
+                if let Some(details) = self.visible_details(claim_id) {
+                    deeds.push(details);
+                }
+            }
+            deeds
+        }
+
+
+        // 🟢 49 GET VOLUNTEER IMPACT - Given an AccountID, total the hours and quantity of
+        // its visible good deeds that were confirmed by their beneficiary. Leave out deeds 
+        // done before done_after or after done_before, as block timestamps. A deed is 
+        // dated by the date in its metadata if it has one, or else by when it was claimed.
+        // Looks at up to 500 deeds per call, starting at start_index. If next_index is 
+        // returned, call again from there and add up the totals.
+        #[ink(message)]
+        pub fn get_volunteer_impact(&self, volunteer: AccountId, 
+            done_after: Option<Timestamp>, done_before: Option<Timestamp>, start_index: u32
+        ) -> ImpactSummary {
+            self.impact_of(IndexKey::Volunteer(volunteer), done_after, done_before, start_index)
+        }
+
+
+        // 🟢 50 GET BENEFICIARY IMPACT - Given an AccountID, total the hours and quantity of
+        // the visible good deeds it confirmed as their beneficiary, in the same way as 
+        // get_volunteer_impact.
+        #[ink(message)]
+        pub fn get_beneficiary_impact(&self, beneficiary: AccountId, 
+            done_after: Option<Timestamp>, done_before: Option<Timestamp>, start_index: u32
+        ) -> ImpactSummary {
+            self.impact_of(IndexKey::Beneficiary(beneficiary), done_after, done_before, start_index)
+        }
+
+
+        // 🟢 54 GET PENDING ISSUED CLAIMS - Given an AccountID, return the claims issued
+        // to it that it has not yet accepted or rejected, up to 25 per call starting at 
+        // start_index. Answering a claim moves the last pending claim into its place.
+        #[ink(message)]
+        pub fn get_pending_issued_claims(&self, subject: AccountId, start_index: u32) -> Vec<Details> {
+            let key = IndexKey::PendingIssued(subject);
+            let end = start_index.saturating_add(MAX_SEARCH_RESULTS).min(self.index_len.get(key).unwrap_or(0));
+            (start_index..end)
+                .filter_map(|index| self.index_items.get((key, index)))
+                .filter_map(|claim_id| self.issued_claims.get(claim_id))
+                .collect()
+        }
+
+
+        // 🟢 65 GET ENDORSEMENT REVOKED AT - for a given claim_id hash and account, when 
+        // (by block timestamp) the account revoked its endorsement, if it has
+        #[ink(message)]
+        pub fn get_endorsement_revoked_at(&self, claim_id: Hash, endorser: AccountId) -> Option<Timestamp> {
+            self.revoked_endorsements.get((claim_id, endorser))
+        }
+
+
+        // 🟢 19 GET CLAIM HISTORY - for a given claim_id hash, get every earlier version 
+        // of the claim, oldest first. The current version is in the claim's Details.
+        #[ink(message)]
+        pub fn get_claim_history(&self, claim_id: Hash) -> Vec<Revision> {
+            let details = self.visible_details(claim_id).unwrap_or_default();
+            let mut history: Vec<Revision> = Vec::new();
+            for revision in 0..details.revision {
+                if let Some(item) = self.claim_revisions.get((claim_id, revision)) {
+                    history.push(item);
+                }
+            }
+            history
+        }
+
+
+        // 🟢 21 IS RETRACTED - for a given claim_id hash, has the claim been retracted by its claimant?
+        #[ink(message)]
+        pub fn is_claim_retracted(&self, claim_id: Hash) -> bool {
+            self.retracted_claims.contains(claim_id)
+        }
+
+
+        // INSTITUTION REGISTRY MESSAGES >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>
+
+        // 🟢 55 SET REGISTRY ADMIN - Hand the institution registry to a new admin, such as
+        // a governance contract [RESTRICTED: REGISTRY ADMIN]
+        #[ink(message)]
+        pub fn set_registry_admin(&mut self, new_admin: AccountId) -> Result<(), Error> {
+            if self.registry_admin != Self::env().caller() {
+                return Err(Error::PermissionDenied)
+            }
+            self.registry_admin = new_admin;
+            Ok(())
+        }
+
+
+        // 🟢 56 ADD INSTITUTION - Add a verified institution to the registry, or update the
+        // name, category and link of one already in it [RESTRICTED: REGISTRY ADMIN]
+        #[ink(message)]
+        pub fn add_institution(&mut self, account: AccountId, name: Vec<u8>, 
+            category: InstitutionCategory, link: Vec<u8>
+        ) -> Result<(), Error> {
+            if self.registry_admin != Self::env().caller() {
+                return Err(Error::PermissionDenied)
+            }
+            if name.len() > 200 || link.len() > 600 {
+                return Err(Error::DataTooLarge)
+            }
+
+            // an update keeps the institution's suspension
+            let suspended = match self.institutions.get(account) {
+                Some(existing) => existing.suspended,
+                None => {
+                    self.institution_accounts.push(&account);
+                    false
+                },
+            };
+            let institution = Institution {
+                account,
+                name: name.clone(),
+                category,
+                link,
+                suspended,
+            };
+            if self.institutions.try_insert(account, &institution).is_err() {
+                return Err(Error::DataTooLarge);
+            }
+
+            // emit an event to register the institution to the chain
+            Self::env().emit_event(InstitutionRegistered {
+                account,
+                name,
+                category,
+            });
+
+            Ok(())
+        }
+
+
+        // 🟢 57 SUSPEND INSTITUTION - Suspend (true) or reinstate (false) an institution in 
+        // the registry. Claims it issued or endorsed while verified keep their flags.
+        // [RESTRICTED: REGISTRY ADMIN]
+        #[ink(message)]
+        pub fn suspend_institution(&mut self, account: AccountId, suspended: bool) -> Result<(), Error> {
+            if self.registry_admin != Self::env().caller() {
+                return Err(Error::PermissionDenied)
+            }
+            let mut institution = match self.institutions.get(account) {
+                Some(institution) => institution,
+                None => return Err(Error::UnknownInstitution),
+            };
+            institution.suspended = suspended;
+            self.institutions.insert(account, &institution);
+
+            // emit an event to register the suspension to the chain
+            Self::env().emit_event(InstitutionSuspended {
+                account,
+                suspended,
+            });
+
+            Ok(())
+        }
+
+
+        // 🟢 58 GET INSTITUTION - for a given AccountID, get its entry in the registry
+        #[ink(message)]
+        pub fn get_institution(&self, account: AccountId) -> Option<Institution> {
+            self.institutions.get(account)
+        }
+
+
+        // 🟢 59 GET INSTITUTIONS - list the registry, up to 25 institutions per call 
+        // starting at start_index, suspended or not
+        #[ink(message)]
+        pub fn get_institutions(&self, start_index: u32) -> Vec<Institution> {
+            let end = start_index.saturating_add(MAX_SEARCH_RESULTS).min(self.institution_accounts.len());
+            (start_index..end)
+                .filter_map(|index| self.institution_accounts.get(index))
+                .filter_map(|account| self.institutions.get(account))
+                .collect()
+        }
+
+
+        // 🟢 60 GET REGISTRY ADMIN - the account that curates the institution registry
+        #[ink(message)]
+        pub fn get_registry_admin(&self) -> AccountId {
+            self.registry_admin
+        }
+
+
+        // REWARD PROGRAM MESSAGES >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>
+        
+        // 🟢 11 Verify Account - returns the total number of claims in a given resume
+        #[ink(message)]
+        pub fn verify_account(&self, owner: AccountId) -> (u32, u32, u32, u32, u32) {
+            // given the AccountID, get the set of each type of claimIDs
+            let work = self.account_claims_workhistory.get(owner).unwrap_or_default().claims.len();
+            let ed = self.account_claims_education.get(owner).unwrap_or_default().claims.len();
+            let expert = self.account_claims_expertise.get(owner).unwrap_or_default().claims.len();
+            let deeds = self.account_claims_gooddeeds.get(owner).unwrap_or_default().claims.len();
+            let ip = self.account_claims_intellectualproperty.get(owner).unwrap_or_default().claims.len();
+
+            (work.try_into().unwrap(), ed.try_into().unwrap(), expert.try_into().unwrap(), deeds.try_into().unwrap(), ip.try_into().unwrap())
+        }
+
+
+        // 🟢 12 Rewards - Set Or Update Reward Root Account [RESTRICTED: ROOT]
+        #[ink(message)]
+        pub fn set_reward_roots(&mut self, newroot: AccountId) -> Result<(), Error> {
+            let caller = Self::env().caller();
+            // if the root is already set, send an error
+            if self.reward_root_set != 1 || self.reward_root == caller {
+                // proceed - set the roots and update the storage
+                self.reward_root = newroot;
+                self.reward_root_set = 1;
+            }
+            else {
+                // error PermissionDenied
+                return Err(Error::PermissionDenied)
+            }
+
+            Ok(())
+        }
+
+
+        // 🟢 13 Rewards - Set/Update Reward Interval and Ammount [RESTRICTED: ROOT]
+        // Reward coin will be given to the account that makes the Xth claim in the system
+        #[ink(message)]
+        pub fn set_reward(&mut self, on: u8, interval: u128, amount: Balance) -> Result<(), Error> {
+            let caller = Self::env().caller();
+            if self.reward_root == caller {
+                // proceed to set the reward program paramteters
+                self.reward_on = on;
+                self.reward_interval = interval;
+                self.reward_amount = amount;
+            }
+            else {
+                // error PermissionDenied
+                return Err(Error::PermissionDenied)
+            }
+            
+            Ok(())
+        }
+
+        // 🟢 14 ADD COIN TO REWARD ACCOUNT [RESTRICTED: ROOT]
+        #[ink(message, payable)]
+        pub fn add_reward_balance(&mut self) -> Result<(), Error> {
+            let caller = Self::env().caller();
+            if self.reward_root == caller {
+                // add the paid in value to the reward_balance
+                let staked: Balance = self.env().transferred_value();
+                let newbalance: Balance = self.reward_balance.saturating_add(staked);
+                self.reward_balance = newbalance;
+            }
+            else {
+                // error PermissionDenied
+                return Err(Error::PermissionDenied)
+            }
+            
+            Ok(())
+        }
+
+
+        // 🟢 15 RETREIVE COIN FROM REWARD ACCOUNT [RESTRICTED: ROOT]
+        // turns reward program off and returns funds to the root
+        #[ink(message)]
+        pub fn shut_down_reward(&mut self) -> Result<(), Error> {
+            let caller = Self::env().caller();
+            if self.reward_root == caller {
+                // set the reward program to off
+                self.reward_on = 0;
+                // refund the coin to the reward root
+                // Check that there is a nonzero balance on the contract > existential deposit
+                if self.env().balance() > 10 && self.reward_balance > 0 {
+                    // pay the root the reward_balance minus 10
+                    let payout: Balance = self.reward_balance.saturating_sub(10);
+                    if self.env().transfer(caller, payout).is_err() {
+                        return Err(Error::PayoutFailed);
+                    }
+                }
+                // if the balance is < 10, Error (ZeroBalance)
+                else {
+                    return Err(Error::ZeroBalance);
+                }
+            }
+            else {
+                // error PermissionDenied
+                return Err(Error::PermissionDenied)
+            }
+            
+            Ok(())
+        }
+
+
+        // 🟢 16 GET CURRENT REWARD BALANCE AND SETTINGS [RESTRICTED: ROOT]
+        #[ink(message)]
+        pub fn get_reward_settings(&self) -> RewardSettings {
+            let caller = Self::env().caller();
+            let mut results = RewardSettings::default();
+            if self.reward_root == caller {
+                let settings = RewardSettings {
+                    reward_on: self.reward_on,
+                    reward_root_set: self.reward_root_set,
+                    reward_root: self.reward_root,
+                    reward_interval: self.reward_interval,
+                    reward_amount: self.reward_amount,
+                    reward_balance: self.reward_balance,
+                    reward_payouts: self.reward_payouts,
+                    claim_counter: self.claim_counter,
+                };
+                results = settings;
+            }
+
+            results
+        }
+
+
+        // INTERNAL FUNCTIONS >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>
+
+        // hash the claimant, claim type and claim data into a claim_id
+        fn hash_claim(claimant: AccountId, claim_type: ClaimType, claim_contents: &[u8]) -> Hash {
+            let encodable = (CLAIM_ID_DOMAIN, claimant, claim_type, claim_contents); // Implements `scale::Encode`
+            let mut claim_hash_u8 = <Sha2x256 as HashOutput>::Type::default(); // 256-bit buffer
+            ink::env::hash_encoded::<Sha2x256, _>(&encodable, &mut claim_hash_u8);
+            Hash::from(claim_hash_u8)
+        }
+
+        // hash the issuer, subject, claim type and claim data into the claim_id of an issued claim
+        fn hash_issued_claim(issuer: AccountId, subject: AccountId, claim_type: ClaimType, 
+            claim_contents: &[u8]
+        ) -> Hash {
+            let encodable = (CLAIM_ID_DOMAIN, subject, claim_type, claim_contents, issuer); // Implements `scale::Encode`
+            let mut claim_hash_u8 = <Sha2x256 as HashOutput>::Type::default(); // 256-bit buffer
+            ink::env::hash_encoded::<Sha2x256, _>(&encodable, &mut claim_hash_u8);
+            Hash::from(claim_hash_u8)
+        }
+
+        // get the claim_ids of a given type in an account's resume
+        fn get_account_claims(&self, claim_type: ClaimType, owner: AccountId) -> Claims {
+            match claim_type {
+                ClaimType::WorkHistory => self.account_claims_workhistory.get(owner),
+                ClaimType::Education => self.account_claims_education.get(owner),
+                ClaimType::Expertise => self.account_claims_expertise.get(owner),
+                ClaimType::GoodDeed => self.account_claims_gooddeeds.get(owner),
+                ClaimType::IntellectualProperty => self.account_claims_intellectualproperty.get(owner),
+                ClaimType::Unknown => None,
+            }.unwrap_or_default()
+        }
+
+        // update the claim_ids of a given type in an account's resume
+        fn set_account_claims(&mut self, claim_type: ClaimType, owner: AccountId, claims: &Claims) {
+            match claim_type {
+                ClaimType::WorkHistory => self.account_claims_workhistory.insert(owner, claims),
+                ClaimType::Education => self.account_claims_education.insert(owner, claims),
+                ClaimType::Expertise => self.account_claims_expertise.insert(owner, claims),
+                ClaimType::GoodDeed => self.account_claims_gooddeeds.insert(owner, claims),
+                ClaimType::IntellectualProperty => self.account_claims_intellectualproperty.insert(owner, claims),
+                ClaimType::Unknown => None,
+            };
+        }
+
+        // the number of claims in the all_claims StorageVec for a given claim type
+        fn all_claims_len(&self, claim_type: ClaimType) -> u32 {
+            match claim_type {
+                ClaimType::WorkHistory => self.all_claims_work.len(),
+                ClaimType::Education => self.all_claims_education.len(),
+                ClaimType::Expertise => self.all_claims_expertise.len(),
+                ClaimType::GoodDeed => self.all_claims_deeds.len(),
+                ClaimType::IntellectualProperty => self.all_claims_ip.len(),
+                ClaimType::Unknown => 0,
+            }
+        }
+
+        // get the claim_id at a given index of the all_claims StorageVec for a given claim type
+        fn get_all_claims(&self, claim_type: ClaimType, index: u32) -> Option<Hash> {
+            match claim_type {
+                ClaimType::WorkHistory => self.all_claims_work.get(index),
+                ClaimType::Education => self.all_claims_education.get(index),
+                ClaimType::Expertise => self.all_claims_expertise.get(index),
+                ClaimType::GoodDeed => self.all_claims_deeds.get(index),
+                ClaimType::IntellectualProperty => self.all_claims_ip.get(index),
+                ClaimType::Unknown => None,
+            }
+        }
+
+        // add a claim_id to the all_claims StorageVec searched for a given claim type,
+        // remembering its position so it can be removed without a search
+        fn push_all_claims(&mut self, claim_type: ClaimType, claim_id: Hash) {
+            self.all_claims_positions.insert(claim_id, &self.all_claims_len(claim_type));
+            match claim_type {
+                ClaimType::WorkHistory => self.all_claims_work.push(&claim_id),
+                ClaimType::Education => self.all_claims_education.push(&claim_id),
+                ClaimType::Expertise => self.all_claims_expertise.push(&claim_id),
+                ClaimType::GoodDeed => self.all_claims_deeds.push(&claim_id),
+                ClaimType::IntellectualProperty => self.all_claims_ip.push(&claim_id),
+                ClaimType::Unknown => (),
+            }
+        }
+
+        // remove a claim_id from the all_claims StorageVec for a given claim type. Its slot
+        // is left holding the zero hash, so no other claim moves and the positions used
+        // as search cursors stay valid.
+        fn remove_all_claims(&mut self, claim_type: ClaimType, claim_id: Hash) {
+            let Some(index) = self.all_claims_positions.take(claim_id) else {
+                return
+            };
+            let empty = Hash::default();
+            match claim_type {
+                ClaimType::WorkHistory => { self.all_claims_work.set(index, &empty); },
+                ClaimType::Education => { self.all_claims_education.set(index, &empty); },
+                ClaimType::Expertise => { self.all_claims_expertise.set(index, &empty); },
+                ClaimType::GoodDeed => { self.all_claims_deeds.set(index, &empty); },
+                ClaimType::IntellectualProperty => { self.all_claims_ip.set(index, &empty); },
+                ClaimType::Unknown => (),
+            }
+        }
+
+        // get the details for a claim IF it is shown or the caller is the owner
+        fn visible_details(&self, claim_id: Hash) -> Option<Details> {
+            let details = self.claim_details.get(claim_id)?;
+            if details.show || details.claimant == Self::env().caller() {
+                Some(details)
+            }
+            else {
+                None
+            }
+        }
+
+        // is the account an institution in the registry that is not suspended?
+        fn is_verified_institution(&self, account: AccountId) -> bool {
+            self.institutions.get(account).is_some_and(|institution| !institution.suspended)
+        }
+
+        // has the account endorsed the claim since its endorsements last started over?
+        // checked against every endorsement, not just the 20 kept in the endorsers list
+        fn is_endorser(&self, claim_id: Hash, details: &Details, account: AccountId) -> bool {
+            self.endorsements.get((claim_id, account)) == Some(details.endorsement_round)
+        }
+
+        // get the IP claim registered for a file hash
+        fn file_hash_claim(&self, file_hash: Hash) -> Option<Details> {
+            self.ip_file_hashes.get(file_hash)
+                .and_then(|claim_id| self.claim_details.get(claim_id))
+        }
+
+        // make sure a file hash is free for the claimant to register
+        fn check_file_hash(&self, claimant: AccountId, file_hash: Hash) -> Result<(), Error> {
+            match self.file_hash_claim(file_hash) {
+                Some(existing) if existing.claimant != claimant => Err(Error::FileHashRegistered),
+                Some(_) => Err(Error::DuplicateClaim),
+                None => Ok(()),
+            }
+        }
+
+        // total the impact of up to 500 good deeds in an index list, starting at start_index
+        fn impact_of(&self, key: IndexKey, done_after: Option<Timestamp>, done_before: Option<Timestamp>,
+            start_index: u32
+        ) -> ImpactSummary {
+            let len = self.index_len.get(key).unwrap_or(0);
+            let end = start_index.saturating_add(MAX_SEARCH_SCAN).min(len);
+            let mut summary = ImpactSummary::default();
+            for index in start_index..end {
+                let Some(claim_id) = self.index_items.get((key, index)) else { continue };
+                if let Some(details) = self.claim_details.get(claim_id) {
+                    summary.add(&details, done_after, done_before);
+                }
+            }
+            if end < len {
+                summary.next_index = Some(end);
+            }
+            summary
+        }
+
+        // get the details for every claim in an account's resume, in ClaimType order
+        fn resume_items(&self, owner: AccountId, include_hidden: bool) -> Vec<Details> {
+            let mut resume: Vec<Details> = Vec::new();
+            for claim_type in ClaimType::ALL {
+                // for each claimID of this type...
+                for claimidhash in self.get_account_claims(claim_type, owner).claims.iter() {
+                    // get the details
+                    let resumeitem = self.claim_details.get(claimidhash).unwrap_or_default();
+                    // then add that resume item to the resume vector if it can be shown
+                    if resumeitem.show || include_hidden {
+                        resume.push(resumeitem);
+                    }
+                }
+                // IP claims the account co-authored follow its own, if their claimant shows them
+                if claim_type == ClaimType::IntellectualProperty {
+                    for claim_id in self.index_claims(IndexKey::CoAuthored(owner)) {
+                        if let Some(resumeitem) = self.claim_details.get(claim_id) {
+                            if resumeitem.show {
+                                resume.push(resumeitem);
+                            }
+                        }
+                    }
+                }
+            }
+            resume
+        }
+
+        // does the claim contain EVERY one of the search strings?
+        fn matches_all_keywords(claim: &[u8], searchstrings: &[String]) -> bool {
+            // We have to convert the u8 vectors to strings so that we can use the contains() function
+            let claimstring = String::from_utf8(claim.to_vec()).unwrap_or_default();
+            searchstrings.iter().all(|keywords| claimstring.contains(keywords.as_str()))
+        }
+
+        // the claim keywords as searched with the match mode
+        fn claim_text(&self, mode: MatchMode, details: &Details) -> String {
+            match mode {
+                MatchMode::Exact => String::from_utf8(details.claim.clone()).unwrap_or_default(),
+                MatchMode::Normalized => self.normalized_claims.get(details.claim_id)
+                    .unwrap_or_else(|| normalize_text(&details.claim)),
+            }
+        }
+
+        // does the claim contain EVERY one of the search strings, compared using the match mode?
+        // For MatchMode::Normalized the search strings must already be normalized.
+        fn claim_matches(&self, mode: MatchMode, details: &Details, searchstrings: &[String]) -> bool {
+            let claimstring = self.claim_text(mode, details);
+            searchstrings.iter().all(|keywords| claimstring.contains(keywords.as_str()))
+        }
+
+        // look at up to max_scan claims of one type starting from start_index and collect 
+        // up to max_results visible claims that pass the options and the matches function
+        fn scan_claims<F>(&self, claim_type: ClaimType, start_index: u32, max_scan: u32, 
+            max_results: u32, options: &SearchOptions, matches: F
+        ) -> SearchPage
+        where
+            F: Fn(&Details) -> bool,
+        {
+            self.scan_list(ClaimList::AllClaims(claim_type), start_index, max_scan, max_results, options, matches)
+        }
+
+        // the number of claim_ids in a list
+        fn list_len(&self, list: ClaimList) -> u32 {
+            match list {
+                ClaimList::AllClaims(claim_type) => self.all_claims_len(claim_type),
+                ClaimList::Index(key) => self.index_len.get(key).unwrap_or(0),
+            }
+        }
+
+        // the claim_id at a given index of a list
+        fn list_get(&self, list: ClaimList, index: u32) -> Option<Hash> {
+            match list {
+                ClaimList::AllClaims(claim_type) => self.get_all_claims(claim_type, index),
+                ClaimList::Index(key) => self.index_items.get((key, index)),
+            }
+        }
+
+        // the positions of a list a scan from start_index still has to look at, from the 
+        // first up to (but not including) the second. A scan walks up from start_index, 
+        // or for SortOrder::NewestFirst down from just below it, where 0 means the end.
+        fn scan_bounds(&self, list: ClaimList, start_index: u32, sort: SortOrder) -> (u32, u32) {
+            let total = self.list_len(list);
+            match sort {
+                SortOrder::NewestFirst if start_index == 0 => (0, total),
+                SortOrder::NewestFirst => (0, start_index.min(total)),
+                _ => (start_index.min(total), total),
+            }
+        }
+
+        // look at up to max_scan claim_ids of a list starting from start_index and collect 
+        // up to max_results visible claims that pass the options and the matches function. 
+        // start_index and next_index are positions in the list, so a search can be 
+        // continued while new claims are added. For SortOrder::NewestFirst the list is 
+        // walked down from just below start_index, and a start_index of 0 starts at the end.
+        // For SortOrder::MostEndorsed all max_scan claim_ids are looked at, keeping the 
+        // max_results most endorsed matches.
+        fn scan_list<F>(&self, list: ClaimList, start_index: u32, max_scan: u32, 
+            max_results: u32, options: &SearchOptions, matches: F
+        ) -> SearchPage
+        where
+            F: Fn(&Details) -> bool,
+        {
+            let newest_first = options.sort == SortOrder::NewestFirst;
+            let most_endorsed = options.sort == SortOrder::MostEndorsed;
+            let (mut low, mut high) = self.scan_bounds(list, start_index, options.sort);
+            let mut page = SearchPage::default();
+            let mut scanned: u32 = 0;
+            while low < high && scanned < max_scan && (most_endorsed || (page.results.len() as u32) < max_results) {
+                let index = if newest_first {
+                    high = high.saturating_sub(1);
+                    high
+                }
+                else {
+                    low = low.saturating_add(1);
+                    low.saturating_sub(1)
+                };
+                scanned = scanned.saturating_add(1);
+                // empty slots left by retracted claims have no details
+                let Some(resumeitem) = self.list_get(list, index)
+                    .and_then(|claimidhash| self.claim_details.get(claimidhash)) else {
+                    continue;
+                };
+                // hidden claims are not included in search results
+                if resumeitem.show && options.accepts(&resumeitem) && matches(&resumeitem) {
+                    page.results.push(resumeitem);
+                    // with MostEndorsed the page is full, so drop the least endorsed, latest found
+                    if page.results.len() as u32 > max_results {
+                        if let Some((position, _)) = page.results.iter().enumerate().rev()
+                            .min_by_key(|(_, details)| details.endorser_count) {
+                            page.results.remove(position);
+                        }
+                    }
+                }
+            }
+            // if there is more to look at, tell the caller where to pick up
+            if low < high {
+                page.next_index = Some(if newest_first { high } else { low });
+            }
+            options.rank(&mut page.results);
+            page
+        }
+
+        // add a claim_id to the end of an index list
+        fn index_add(&mut self, key: IndexKey, claim_id: Hash) {
+            if self.index_positions.contains((key, claim_id)) {
+                return
+            }
+            let len = self.index_len.get(key).unwrap_or(0);
+            self.index_items.insert((key, len), &claim_id);
+            self.index_positions.insert((key, claim_id), &len);
+            self.index_len.insert(key, &len.saturating_add(1));
+        }
+
+        // every claim_id in an index list
+        fn index_claims(&self, key: IndexKey) -> Vec<Hash> {
+            (0..self.index_len.get(key).unwrap_or(0))
+                .filter_map(|index| self.index_items.get((key, index)))
+                .collect()
+        }
+
+        // remove a claim_id from an index list by moving the last claim_id into its place
+        fn index_remove(&mut self, key: IndexKey, claim_id: Hash) {
+            let Some(position) = self.index_positions.take((key, claim_id)) else {
+                return
+            };
+            let last = self.index_len.get(key).unwrap_or(0).saturating_sub(1);
+            if position != last {
+                let moved = self.index_items.get((key, last)).unwrap_or_default();
+                self.index_items.insert((key, position), &moved);
+                self.index_positions.insert((key, moved), &position);
+            }
+            self.index_items.remove((key, last));
+            if last == 0 {
+                self.index_len.remove(key);
+            }
+            else {
+                self.index_len.insert(key, &last);
+            }
+        }
+
+        // add a claim to the keyword index under each of its keywords
+        fn index_keywords(&mut self, claim_type: ClaimType, claim_id: Hash, claim: &[u8]) {
+            for word in keyword_tokens(claim).iter() {
+                self.index_add(IndexKey::Keyword(claim_type, keyword_hash(word)), claim_id);
+            }
+        }
+
+        // remove a claim from the keyword index under each of its keywords
+        fn unindex_keywords(&mut self, claim_type: ClaimType, claim_id: Hash, claim: &[u8]) {
+            for word in keyword_tokens(claim).iter() {
+                self.index_remove(IndexKey::Keyword(claim_type, keyword_hash(word)), claim_id);
+            }
+        }
+
+        // replace the keywords, link and metadata of a claim owned by the caller, 
+        // saving the previous version to the claim's revision history
+        fn amend(&mut self, claim_id: Hash, new_keywords_or_description: Vec<u8>, 
+            new_url_link_to_see_more: Vec<u8>, new_metadata: ClaimMetadata, keep_endorsements: bool
+        ) -> Result<(), Error> {
+            
+            // first, get the details and make sure the caller owns this claimID
+            let caller = Self::env().caller();
+            let mut details = match self.claim_details.get(claim_id) {
+                Some(details) => details,
+                None => return Err(Error::NonexistentClaim),
+            };
+            if details.claimant != caller {
+                return Err(Error::CallerNotOwner)
+            }
+
+            // an issued claim says what its issuer wrote, so only its link can change
+            if details.issuer.is_some() && new_keywords_or_description != details.claim {
+                return Err(Error::PermissionDenied)
+            }
+
+            // a claim made with metadata keeps metadata of the same kind, and a 
+            // claim made without metadata is amended with plain keywords
+            if core::mem::discriminant(&new_metadata) != core::mem::discriminant(&details.metadata) {
+                return Err(Error::InvalidMetadata)
+            }
+            new_metadata.validate(details.claimtype)?;
+
+            // if the data is too big or has too many keywords to index, send an error
+            if new_keywords_or_description.len() > 600 || new_url_link_to_see_more.len() > 600 
+                || keyword_tokens(&new_keywords_or_description).len() > MAX_INDEXED_KEYWORDS {
+                return Err(Error::DataTooLarge)
+            }
+
+            // if the IP file changed, the new file hash must be free to register
+            let new_file_hash = new_metadata.file_hash().or(details.file_hash);
+            if let Some(file_hash) = new_file_hash {
+                if Some(file_hash) != details.file_hash {
+                    self.check_file_hash(caller, file_hash)?;
+                }
+            }
+
+            // did the content change, rather than just the link?
+            let content_changed = new_keywords_or_description != details.claim 
+                || new_metadata != details.metadata;
+
+            // save the current version to the revision history
+            let previous = Revision {
+                revision: details.revision,
+                claim: details.claim,
+                link: details.link,
+                endorser_count: details.endorser_count,
+                endorsers: details.endorsers.clone(),
+                metadata: details.metadata,
+            };
+            if self.claim_revisions.try_insert((claim_id, details.revision), &previous).is_err() {
+                return Err(Error::DataTooLarge);
+            }
+
+            // update the keyword index and the normalized search text to the new version
+            self.unindex_keywords(details.claimtype, claim_id, &previous.claim);
+            self.index_keywords(details.claimtype, claim_id, &new_keywords_or_description);
+            self.normalized_claims.insert(claim_id, &normalize_text(&new_keywords_or_description));
+
+            // update the claim to the new version
+            details.claim = new_keywords_or_description;
+            details.link = new_url_link_to_see_more;
+            details.metadata = new_metadata;
+            details.revision = details.revision.saturating_add(1);
+            // the beneficiary confirmed the earlier version, so they have to confirm again
+            details.deed_confirmed = false;
+            // and so did the co-authors, if the work itself changed
+            if content_changed && !details.coauthors.is_empty() {
+                for coauthor in details.coauthors.iter_mut() {
+                    coauthor.confirmed = false;
+                    self.index_remove(IndexKey::CoAuthored(coauthor.account), claim_id);
+                }
+                details.coauthors_confirmed = details.coauthors.iter().all(|c| c.confirmed);
+            }
+
+            if !keep_endorsements {
+                // flag the current endorsers as having endorsed an earlier version
+                for endorser in details.endorsers.iter() {
+                    if *endorser != caller && !details.earlier_endorsers.contains(endorser) {
+                        // if there are already 20, kick out the oldest
+                        if details.earlier_endorsers.len() > 19 {
+                            details.earlier_endorsers.remove(0);
+                        }
+                        details.earlier_endorsers.push(*endorser);
+                    }
+                }
+                // and start the endorsements over in a new round
+                details.endorsers = vec![caller];
+                details.verified_endorsers = <Vec<AccountId>>::default();
+                details.endorser_count = 0;
+                details.endorsement_round = details.endorsement_round.saturating_add(1);
+            }
+
+            // move the file hash registration to the new file
+            if new_file_hash != details.file_hash {
+                if let Some(old_file_hash) = details.file_hash {
+                    self.ip_file_hashes.remove(old_file_hash);
+                }
+                if let Some(file_hash) = new_file_hash {
+                    self.ip_file_hashes.insert(file_hash, &claim_id);
+                }
+                details.file_hash = new_file_hash;
+            }
+
+            // Update the claim_map
+            if self.claim_details.try_insert(claim_id, &details).is_err() {
+                return Err(Error::DataTooLarge);
+            }
+
+            // emit an event to register the amendment to the chain
+            Self::env().emit_event(ClaimAmended {
+                claimant: caller,
+                claim_id,
+                revision: details.revision,
+                endorsements_carried_over: keep_endorsements,
+            });
+
+            Ok(())
+        }
+
+        // set up the details for a new claim by the claimant, ready for store_claim
+        fn draft_claim(claimant: AccountId, claim_type: ClaimType, 
+            keywords_or_description: Vec<u8>, url_link_to_see_more: Vec<u8>
+        ) -> Details {
+            // create the claim_hash by hashing the claimant, claim type and claim data
+            let claim_hash = Self::hash_claim(claimant, claim_type, &keywords_or_description);
+
+            Details {
+                claimtype: claim_type,
+                claimant,
+                claim: keywords_or_description,
+                claim_id: claim_hash,
+                endorser_count: 0,
+                link: url_link_to_see_more,
+                show: true,
+                endorsers: vec![claimant],
+                revision: 0,
+                earlier_endorsers: <Vec<AccountId>>::default(),
+                created_at: Self::env().block_timestamp(),
+                created_block: Self::env().block_number(),
+                last_endorsed_at: Timestamp::default(),
+                tenure: None,
+                metadata: ClaimMetadata::None,
+                file_hash: None,
+                coauthors: <Vec<CoAuthor>>::default(),
+                coauthors_confirmed: true,
+                license_terms: None,
+                previous_owners: <Vec<AccountId>>::default(),
+                beneficiary: None,
+                deed_confirmed: false,
+                impact: None,
+                issuer: None,
+                issuer_verified: false,
+                verified_endorsers: <Vec<AccountId>>::default(),
+                endorsement_round: 0,
+            }
+        }
+
+        // remove a claim issued to the subject from the pending issued claims
+        fn take_issued_claim(&mut self, subject: AccountId, claim_id: Hash) -> Result<Details, Error> {
+            let details = match self.issued_claims.get(claim_id) {
+                Some(details) => details,
+                None => return Err(Error::NonexistentClaim),
+            };
+            if details.claimant != subject {
+                return Err(Error::CallerNotOwner)
+            }
+            self.issued_claims.remove(claim_id);
+            self.index_remove(IndexKey::PendingIssued(subject), claim_id);
+            Ok(details)
+        }
+
+        // THE CLAIM PIPELINE - every new claim, whatever its type, goes through here.
+        // Validates the data, stores the details, updates the account and search 
+        // indexes, emits the claim event and runs the reward program.
+        fn store_claim(&mut self, new_details: Details) -> Result<(), Error> {
+            let claimant = new_details.claimant;
+            let claim_type = new_details.claimtype;
+            let claim_hash = new_details.claim_id;
+
+            self.check_claim(&new_details)?;
+
+            // set the contract storage for this claim...
+            // add this claim to the claim_details map
+            if self.claim_details.try_insert(claim_hash, &new_details).is_err() {
+                return Err(Error::DataTooLarge);
+            }
+
+            // register the file hash of an IP claim
+            if let Some(file_hash) = new_details.file_hash {
+                self.ip_file_hashes.insert(file_hash, &claim_hash);
+            }
+
+            // get the current set of claims of this type for this account
+            let mut currentclaims = self.get_account_claims(claim_type, claimant);
+            // if the account is at the limit for this claim type, kick out the oldest
+            if currentclaims.claims.len() >= claim_type.max_account_claims() {
+                currentclaims.claims.remove(0);
+            }
+            // add the claim hash to the Claims.claims vector of claim_id hashes
+            currentclaims.claims.push(claim_hash);
+            // update the account_claims mapping
+            self.set_account_claims(claim_type, claimant, &currentclaims);
+
+            // add this claim to the all_claims StorageVec for this type
+            self.push_all_claims(claim_type, claim_hash);
+            // and to the keyword index for this type, saving the normalized search text
+            self.index_keywords(claim_type, claim_hash, &new_details.claim);
+            self.normalized_claims.insert(claim_hash, &normalize_text(&new_details.claim));
+            // a good deed is also added to its volunteer's list, which keeps every deed for impact totals
+            if claim_type == ClaimType::GoodDeed {
+                self.index_add(IndexKey::Volunteer(claimant), claim_hash);
+            }
+
+            // Emit an event to register the claim to the chain
+            Self::emit_claim_made(claim_type, claimant, new_details.claim, claim_hash);
+
+            // run the reward program for this claim
+            self.reward_claimant(claimant)
+        }
+
+        // make sure a new claim can be stored
+        fn check_claim(&self, new_details: &Details) -> Result<(), Error> {
+            let claimant = new_details.claimant;
+            let claim_type = new_details.claimtype;
+            let claim_hash = new_details.claim_id;
+
+            // Unknown claims cannot be stored
+            if claim_type == ClaimType::Unknown {
+                return Err(Error::InvalidClaimType)
+            }
+
+            // if the data is too big or has too many keywords to index, send an error
+            if new_details.claim.len() > 600 || new_details.link.len() > 600 
+                || keyword_tokens(&new_details.claim).len() > MAX_INDEXED_KEYWORDS {
+                return Err(Error::DataTooLarge)
+            }
+
+            // only work history and education claims have a tenure, and it must make sense
+            if let Some(tenure) = &new_details.tenure {
+                tenure.validate(claim_type)?;
+            }
+            new_details.metadata.validate(claim_type)?;
+
+            // every IP claim records the hash of its file
+            if claim_type == ClaimType::IntellectualProperty && new_details.file_hash.is_none() {
+                return Err(Error::MissingFileHash)
+            }
+
+            // only IP claims have a file hash, and the first account to register it keeps it
+            if let Some(file_hash) = new_details.file_hash {
+                if claim_type != ClaimType::IntellectualProperty {
+                    return Err(Error::InvalidClaimType)
+                }
+                self.check_file_hash(claimant, file_hash)?;
+            }
+
+            // Check to make sure the claim is not a duplicate
+            if self.claim_details.contains(claim_hash) {
+                // if TRUE, issue an error
+                return Err(Error::DuplicateClaim)
+            }
+
+            // Check to make sure the claim was not retracted
+            if self.retracted_claims.contains(claim_hash) {
+                return Err(Error::RetractedClaim)
+            }
+
+            Ok(())
+        }
+
+        // emit the claim event that matches the claim type
+        fn emit_claim_made(claim_type: ClaimType, claimant: AccountId, claim: Vec<u8>, claim_id: Hash) {
+            match claim_type {
+                ClaimType::WorkHistory => Self::env().emit_event(ClaimMadeWorkHistory {
+                    claimant, claim, claim_id
+                }),
+                ClaimType::Education => Self::env().emit_event(ClaimMadeEducation {
+                    claimant, claim, claim_id
+                }),
+                ClaimType::Expertise => Self::env().emit_event(ClaimMadeExpertise {
+                    claimant, claim, claim_id
+                }),
+                ClaimType::GoodDeed => Self::env().emit_event(ClaimMadeGoodDeed {
+                    claimant, claim, claim_id
+                }),
+                ClaimType::IntellectualProperty => Self::env().emit_event(ClaimMadeIntellectualProperty {
+                    claimant, claim, claim_id
+                }),
+                ClaimType::Unknown => (),
+            }
+        }
+
+        // REWARD PROGRAM ACTIONS... count the new claim and pay out a reward to the
+        // claimant IF this is the Xth claim and the reward program can afford it
+        fn reward_claimant(&mut self, claimant: AccountId) -> Result<(), Error> {
+            // update the claim_counter 
+            self.claim_counter = self.claim_counter.wrapping_add(1);
+            // IF conditions are met THEN payout a reward
+            let min = self.reward_amount.saturating_add(10);
+            let payout: Balance = self.reward_amount;
+            if self.reward_on == 1 && self.reward_balance > payout && self.env().balance() > min
+            && self.claim_counter.checked_rem_euclid(self.reward_interval) == Some(0) {
+                // payout
+                if self.env().transfer(claimant, payout).is_err() {
+                    return Err(Error::PayoutFailed);
+                }
+                // update reward_balance
+                self.reward_balance = self.reward_balance.saturating_sub(payout);
+                // update reward_payouts
+                self.reward_payouts = self.reward_payouts.saturating_add(payout);
+                // emit an event to register the reward to the chain
+                Self::env().emit_event(AccountRewardedLifeAndWork {
+                    claimant,
+                    reward: payout
+                });
+            }
+            Ok(())
+        }
+
+    }
+
+    // normalize text for searching: lowercase it, turn anything that is not a letter 
+    // or a digit into a space, and collapse the spaces so words are separated by one space
+    fn normalize_text(text: &[u8]) -> String {
+        let text = String::from_utf8_lossy(text);
+        let mut normalized = String::new();
+        for word in text.split(|character: char| !character.is_alphanumeric()) {
+            if word.is_empty() {
+                continue;
+            }
+            if !normalized.is_empty() {
+                normalized.push(' ');
+            }
+            normalized.extend(word.chars().flat_map(char::to_lowercase));
+        }
+        normalized
+    }
+
+    // split claim keywords into distinct normalized words, in the order they first appear
+    fn keyword_tokens(claim: &[u8]) -> Vec<String> {
+        let mut words: Vec<String> = Vec::new();
+        for word in normalize_text(claim).split(' ') {
+            if !word.is_empty() && !words.iter().any(|known| known == word) {
+                words.push(String::from(word));
+            }
+        }
+        words
+    }
+
+    // the hash of a normalized keyword, used as its key in the keyword index
+    fn keyword_hash(word: &str) -> Hash {
+        let mut keyword_hash_u8 = <Sha2x256 as HashOutput>::Type::default(); // 256-bit buffer
+        ink::env::hash_bytes::<Sha2x256>(word.as_bytes(), &mut keyword_hash_u8);
+        Hash::from(keyword_hash_u8)
+    }
+
+    // lowercase hexadecimal text for some bytes, such as a file hash
+    fn hex_bytes(bytes: &[u8]) -> Vec<u8> {
+        const DIGITS: &[u8] = b"0123456789abcdef";
+        let mut hex = Vec::with_capacity(bytes.len().saturating_mul(2));
+        for byte in bytes {
+            hex.push(DIGITS[usize::from(byte >> 4)]);
+            hex.push(DIGITS[usize::from(byte & 0x0f)]);
+        }
+        hex
+    }
+
+    // the number of days from 1970-01-01 to a YYYYMMDD date, or None if it is not a real date
+    fn day_number(date: u32) -> Option<i64> {
+        let year = i64::from(date / 10000);
+        let month = i64::from(date / 100 % 100);
+        let day = i64::from(date % 100);
+        let leap = (year % 4 == 0 && year % 100 != 0) || year % 400 == 0;
+        let days_in_month = match month {
+            1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+            4 | 6 | 9 | 11 => 30,
+            2 if leap => 29,
+            2 => 28,
+            _ => return None,
+        };
+        if year < 1 || day < 1 || day > days_in_month {
+            return None
+        }
+        // count from March so the leap day falls at the end of the year
+        let year = if month <= 2 { year - 1 } else { year };
+        let era = year.div_euclid(400);
+        let year_of_era = year - era * 400;
+        let day_of_year = (153 * (if month > 2 { month - 3 } else { month + 9 }) + 2) / 5 + day - 1;
+        let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+        Some(era * 146097 + day_of_era - 719468)
+    }
+
+
+    // SEARCH QUERY LANGUAGE >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>
+
+    // A parsed boolean search query
+    enum QueryNode {
+        // a term or phrase the claim must contain as raw text
+        Term(String),
+        // normalized words the claim must contain as whole words, one after another
+        Words(String),
+        Not(Box<QueryNode>),
+        And(Vec<QueryNode>),
+        Or(Vec<QueryNode>),
+    }
+
+    impl QueryNode {
+        // does the claim text satisfy this query?
+        fn matches(&self, claimstring: &str) -> bool {
+            match self {
+                QueryNode::Term(term) => claimstring.contains(term.as_str()),
+                QueryNode::Words(words) => contains_words(claimstring, words),
+                QueryNode::Not(node) => !node.matches(claimstring),
+                QueryNode::And(nodes) => nodes.iter().all(|node| node.matches(claimstring)),
+                QueryNode::Or(nodes) => nodes.iter().any(|node| node.matches(claimstring)),
+            }
+        }
+    }
+
+    // does the normalized text contain the normalized words, starting and ending on word boundaries?
+    fn contains_words(text: &str, words: &str) -> bool {
+        text.match_indices(words).any(|(start, _)| {
+            let end = start.saturating_add(words.len());
+            (start == 0 || text.as_bytes()[start.saturating_sub(1)] == b' ')
+                && (end == text.len() || text.as_bytes()[end] == b' ')
+        })
+    }
+
+    // The pieces a search query is split into before parsing
+    enum QueryToken {
+        Open,
+        Close,
+        And,
+        Or,
+        Not,
+        Term(String),
+    }
+
+    // split a search query into tokens, preparing each term for the match mode
+    fn tokenize_query(query: &str, mode: MatchMode) -> Result<Vec<QueryToken>, Error> {
+        let prepare = |term: &str| -> Result<QueryToken, Error> {
+            let term = match mode {
+                MatchMode::Exact => String::from(term),
+                MatchMode::Normalized => normalize_text(term.as_bytes()),
+            };
+            // a term that is empty would match every claim
+            if term.is_empty() {
+                return Err(Error::InvalidQuery)
+            }
+            Ok(QueryToken::Term(term))
+        };
+
+        let mut tokens: Vec<QueryToken> = Vec::new();
+        let mut rest = query.trim_start();
+        while let Some(next) = rest.chars().next() {
+            if next == '(' || next == ')' {
+                tokens.push(if next == '(' { QueryToken::Open } else { QueryToken::Close });
+                rest = &rest[1..];
+            }
+            else if next == '"' {
+                // a quoted phrase runs to the next quote
+                let Some(end) = rest[1..].find('"') else {
+                    return Err(Error::InvalidQuery)
+                };
+                tokens.push(prepare(&rest[1..end.saturating_add(1)])?);
+                rest = &rest[end.saturating_add(2)..];
+            }
+            else {
+                // a word runs to the next space, parenthesis or quote
+                let end = rest.find(|character: char| character.is_whitespace() 
+                    || character == '(' || character == ')' || character == '"')
+                    .unwrap_or(rest.len());
+                let word = &rest[..end];
+                tokens.push(match word {
+                    "AND" => QueryToken::And,
+                    "OR" => QueryToken::Or,
+                    "NOT" => QueryToken::Not,
+                    _ => prepare(word)?,
+                });
+                rest = &rest[end..];
+            }
+            rest = rest.trim_start();
+        }
+        Ok(tokens)
+    }
+
+    // A recursive descent parser over the tokens of a search query
+    struct QueryParser {
+        tokens: Vec<QueryToken>,
+        position: usize,
+        terms: usize,
+        mode: MatchMode,
+    }
+
+    impl QueryParser {
+        fn peek(&self) -> Option<&QueryToken> {
+            self.tokens.get(self.position)
+        }
+
+        // either := both ( OR both )*
+        fn parse_either(&mut self, depth: usize) -> Result<QueryNode, Error> {
+            let mut nodes = vec![self.parse_both(depth)?];
+            while matches!(self.peek(), Some(QueryToken::Or)) {
+                self.position = self.position.saturating_add(1);
+                nodes.push(self.parse_both(depth)?);
+            }
+            Ok(if nodes.len() == 1 { nodes.remove(0) } else { QueryNode::Or(nodes) })
+        }
+
+        // both := single ( [AND] single )*
+        fn parse_both(&mut self, depth: usize) -> Result<QueryNode, Error> {
+            let mut nodes = vec![self.parse_single(depth)?];
+            loop {
+                match self.peek() {
+                    Some(QueryToken::And) => {
+                        self.position = self.position.saturating_add(1);
+                    }
+                    Some(QueryToken::Open) | Some(QueryToken::Not) | Some(QueryToken::Term(_)) => (),
+                    _ => break,
+                }
+                nodes.push(self.parse_single(depth)?);
+            }
+            Ok(if nodes.len() == 1 { nodes.remove(0) } else { QueryNode::And(nodes) })
+        }
+
+        // single := NOT single | ( either ) | term
+        fn parse_single(&mut self, depth: usize) -> Result<QueryNode, Error> {
+            if depth >= MAX_QUERY_DEPTH {
+                return Err(Error::InvalidQuery)
+            }
+            let Some(token) = self.tokens.get_mut(self.position) else {
+                return Err(Error::InvalidQuery)
+            };
+            let node = match token {
+                QueryToken::Not => {
+                    self.position = self.position.saturating_add(1);
+                    QueryNode::Not(Box::new(self.parse_single(depth.saturating_add(1))?))
+                }
+                QueryToken::Open => {
+                    self.position = self.position.saturating_add(1);
+                    let node = self.parse_either(depth.saturating_add(1))?;
+                    if !matches!(self.peek(), Some(QueryToken::Close)) {
+                        return Err(Error::InvalidQuery)
+                    }
+                    self.position = self.position.saturating_add(1);
+                    node
+                }
+                QueryToken::Term(term) => {
+                    let term = core::mem::take(term);
+                    self.position = self.position.saturating_add(1);
+                    self.terms = self.terms.saturating_add(1);
+                    if self.terms > MAX_QUERY_TERMS {
+                        return Err(Error::InvalidQuery)
+                    }
+                    match self.mode {
+                        MatchMode::Exact => QueryNode::Term(term),
+                        MatchMode::Normalized => QueryNode::Words(term),
+                    }
+                }
+                _ => return Err(Error::InvalidQuery),
+            };
+            Ok(node)
+        }
+    }
+
+    // parse a search query into a QueryNode, preparing each term for the match mode
+    fn parse_query(query: &[u8], mode: MatchMode) -> Result<QueryNode, Error> {
+        let Ok(query) = core::str::from_utf8(query) else {
+            return Err(Error::InvalidQuery)
+        };
+        let mut parser = QueryParser {
+            tokens: tokenize_query(query, mode)?,
+            position: 0,
+            terms: 0,
+            mode,
+        };
+        let node = parser.parse_either(0)?;
+        // everything in the query must have been used
+        if parser.position != parser.tokens.len() {
+            return Err(Error::InvalidQuery)
+        }
+        Ok(node)
+    }
+
+
+    // END OF CONTRACT LOGIC
+
+
+    // TESTS >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        type Environment = ink::env::DefaultEnvironment;
+
+        fn set_caller(account: AccountId) {
+            ink::env::test::set_caller::<Environment>(account);
+        }
+
+        fn accounts() -> ink::env::test::DefaultAccounts<Environment> {
+            ink::env::test::default_accounts::<Environment>()
+        }
+
+        fn alice() -> AccountId {
+            accounts().alice
+        }
+
+        // deploy the contract under its own funded account, so it can pay out
+        fn funded_contract() -> ContractStorage {
+            let contract_account = AccountId::from([0xcc; 32]);
+            ink::env::test::set_callee::<Environment>(contract_account);
+            ink::env::test::set_account_balance::<Environment>(contract_account, 1_000_000);
+            ContractStorage::new()
+        }
+
+        fn balance(account: AccountId) -> Balance {
+            ink::env::test::get_account_balance::<Environment>(account).unwrap_or_default()
+        }
+
+        // call purchase_license as the buyer, paying the payment
+        fn buy_license(contract: &mut ContractStorage, buyer: AccountId, claim_id: Hash, 
+            payment: Balance
+        ) -> Result<(), Error> {
+            set_caller(buyer);
+            ink::env::test::set_value_transferred::<Environment>(payment);
+            contract.purchase_license(claim_id)
+        }
+
+        // does the query parse in the match mode and match the text?
+        fn query_matches(query: &str, mode: MatchMode, text: &str) -> bool {
+            parse_query(query.as_bytes(), mode).unwrap().matches(text)
+        }
+
+        // make a work history claim with a tenure running from start_date to end_date
+        fn make_job(contract: &mut ContractStorage, role: &str, start_date: u32, 
+            end_date: Option<u32>
+        ) -> Result<(), Error> {
+            contract.make_claim_with_tenure(ClaimType::WorkHistory, role.as_bytes().to_vec(), 
+                Vec::new(), Tenure {
+                    organization: b"Wave Technologies".to_vec(),
+                    role_or_degree: role.as_bytes().to_vec(),
+                    start_date,
+                    end_date,
+                })
+        }
+
+        // make an IP claim for the file hash [file; 32] as the caller and return its claim_id
+        fn make_ip(contract: &mut ContractStorage, title: &str, file: u8) -> Hash {
+            let metadata = ClaimMetadata::IntellectualPropertyV1(IpMeta {
+                title: title.as_bytes().to_vec(),
+                keywords: Vec::new(),
+                file_hash: Hash::from([file; 32]),
+            });
+            contract.make_claim_with_metadata(ClaimType::IntellectualProperty, metadata, 
+                Vec::new()).unwrap();
+            contract.get_ip_claim_by_file_hash(Hash::from([file; 32])).unwrap().claim_id
+        }
+
+        // make expertise claims "skill 0" to "skill {count - 1}" as alice
+        fn make_skills(contract: &mut ContractStorage, first: u32, count: u32) {
+            set_caller(alice());
+            for number in first..first.saturating_add(count) {
+                contract.make_claim(ClaimType::Expertise, format!("skill {number}").into_bytes(), 
+                    Vec::new(), None).unwrap();
+            }
+        }
+
+        // run a query search for "skill" and return the claims found and the cursor
+        fn skill_page(contract: &ContractStorage, sort: SortOrder, start_index: u32, 
+            max_results: u32
+        ) -> (Vec<String>, Option<u32>) {
+            let options = SearchOptions { mode: MatchMode::Normalized, sort, ..Default::default() };
+            let page = contract.query_claims(ClaimType::Expertise, b"skill".to_vec(), options, 
+                start_index, MAX_SEARCH_SCAN, max_results).unwrap();
+            let claims = page.results.iter()
+                .map(|details| String::from_utf8(details.claim.clone()).unwrap())
+                .collect();
+            (claims, page.next_index)
+        }
+
+        #[ink::test]
+        fn query_and_binds_tighter_than_or() {
+            // rust OR (ink AND substrate)
+            assert!(query_matches("rust OR ink AND substrate", MatchMode::Exact, "rust"));
+            assert!(query_matches("rust OR ink AND substrate", MatchMode::Exact, "ink substrate"));
+            assert!(!query_matches("rust OR ink AND substrate", MatchMode::Exact, "ink"));
+            // parentheses override the precedence
+            assert!(!query_matches("(rust OR ink) AND substrate", MatchMode::Exact, "rust"));
+            // NOT applies to the single term after it
+            assert!(query_matches("NOT rust OR ink", MatchMode::Exact, "rust ink"));
+            assert!(!query_matches("NOT (rust OR ink)", MatchMode::Exact, "rust"));
+        }
+
+        #[ink::test]
+        fn query_terms_without_an_operator_are_anded() {
+            assert!(query_matches("rust ink", MatchMode::Exact, "rust and ink"));
+            assert!(!query_matches("rust ink", MatchMode::Exact, "rust"));
+            assert!(query_matches("rust ink OR go", MatchMode::Exact, "go"));
+            // lowercase operators are ordinary terms
+            assert!(!query_matches("rust or go", MatchMode::Exact, "go"));
+            assert!(query_matches("rust or go", MatchMode::Exact, "rust or go"));
+        }
+
+        #[ink::test]
+        fn query_phrases_and_words() {
+            assert!(query_matches("\"smart contract\"", MatchMode::Exact, "smart contract dev"));
+            assert!(!query_matches("\"smart contract\"", MatchMode::Exact, "contract smart"));
+            // normalized terms are whole words, and phrases can span several of them
+            assert!(!query_matches("ink", MatchMode::Normalized, "thinking"));
+            assert!(query_matches("Ink", MatchMode::Normalized, "rust ink"));
+            assert!(query_matches("rust NOT intern", MatchMode::Normalized, "rust international"));
+            assert!(!query_matches("rust NOT intern", MatchMode::Normalized, "rust intern"));
+            assert!(query_matches("\"Smart-Contract dev\"", MatchMode::Normalized, "ink smart contract dev"));
+            assert!(!query_matches("\"smart contract dev\"", MatchMode::Normalized, "smart contract developer"));
+            // exact terms are raw text
+            assert!(query_matches("ink", MatchMode::Exact, "thinking"));
+        }
+
+        #[ink::test]
+        fn query_rejects_malformed_queries() {
+            for query in ["", "(rust", "rust)", "(rust))", "()", "\"rust", "rust \"ink", 
+                "AND rust", "rust OR", "rust NOT", "rust AND OR ink", "\"\"", "!!!"] {
+                assert_eq!(parse_query(query.as_bytes(), MatchMode::Normalized).err(), 
+                    Some(Error::InvalidQuery), "{query}");
+            }
+            assert_eq!(parse_query(&[0xff, 0xfe], MatchMode::Exact).err(), Some(Error::InvalidQuery));
+        }
+
+        #[ink::test]
+        fn query_depth_and_term_limits() {
+            let nested = |depth: usize| format!("{}rust{}", "(".repeat(depth), ")".repeat(depth));
+            assert!(parse_query(nested(MAX_QUERY_DEPTH - 1).as_bytes(), MatchMode::Exact).is_ok());
+            assert_eq!(parse_query(nested(MAX_QUERY_DEPTH).as_bytes(), MatchMode::Exact).err(), 
+                Some(Error::InvalidQuery));
+            let negated = |depth: usize| format!("{}rust", "NOT ".repeat(depth));
+            assert!(parse_query(negated(MAX_QUERY_DEPTH - 1).as_bytes(), MatchMode::Exact).is_ok());
+            assert_eq!(parse_query(negated(MAX_QUERY_DEPTH).as_bytes(), MatchMode::Exact).err(), 
+                Some(Error::InvalidQuery));
+
+            let terms = |count: usize| ["rust"].repeat(count).join(" OR ");
+            assert!(parse_query(terms(MAX_QUERY_TERMS).as_bytes(), MatchMode::Exact).is_ok());
+            assert_eq!(parse_query(terms(MAX_QUERY_TERMS + 1).as_bytes(), MatchMode::Exact).err(), 
+                Some(Error::InvalidQuery));
+
+            // and the query message limits the size of the query itself
+            let contract = ContractStorage::new();
+            assert_eq!(contract.query_claims(ClaimType::Expertise, vec![b'a'; 601], 
+                SearchOptions::default(), 0, 10, 10), Err(Error::DataTooLarge));
+        }
+
+        #[ink::test]
+        fn day_number_handles_leap_years() {
+            assert_eq!(day_number(19700101), Some(0));
+            assert_eq!(day_number(20240229).zip(day_number(20240228)).map(|(a, b)| a - b), Some(1));
+            assert_eq!(day_number(20240301).zip(day_number(20240228)).map(|(a, b)| a - b), Some(2));
+            assert_eq!(day_number(20250101).zip(day_number(20240101)).map(|(a, b)| a - b), Some(366));
+            assert!(day_number(20000229).is_some());
+            assert!(day_number(20230229).is_none());
+            assert!(day_number(19000229).is_none());
+        }
+
+        #[ink::test]
+        fn day_number_rejects_invalid_dates() {
+            for date in [0, 20240001, 20241301, 20240100, 20240132, 20240431, 101, 99999999] {
+                assert!(day_number(date).is_none(), "{date}");
+            }
+        }
+
+        #[ink::test]
+        fn tenure_dates_are_validated() {
+            let mut contract = ContractStorage::new();
+            assert_eq!(make_job(&mut contract, "intern", 20230229, None), Err(Error::InvalidDateRange));
+            assert_eq!(make_job(&mut contract, "intern", 20240101, Some(20231231)), 
+                Err(Error::InvalidDateRange));
+            assert_eq!(make_job(&mut contract, "intern", 20240101, Some(20240230)), 
+                Err(Error::InvalidDateRange));
+            assert_eq!(make_job(&mut contract, "intern", 20240229, Some(20240229)), Ok(()));
+        }
+
+        #[ink::test]
+        fn timeline_finds_gaps_between_tenures() {
+            set_caller(alice());
+            let mut contract = ContractStorage::new();
+            make_job(&mut contract, "cto", 20230301, None).unwrap();
+            make_job(&mut contract, "engineer", 20200101, Some(20201231)).unwrap();
+            // starts the day after the last one ended, so there is no gap
+            make_job(&mut contract, "lead", 20210101, Some(20211231)).unwrap();
+            // inside the lead role, so its early end does not open a gap
+            make_job(&mut contract, "mentor", 20210601, Some(20210630)).unwrap();
+            make_job(&mut contract, "consultant", 20220301, Some(20220331)).unwrap();
+            // starts while the cto role is current
+            make_job(&mut contract, "advisor", 20240101, Some(20240131)).unwrap();
+
+            let timeline = contract.get_timeline(alice(), ClaimType::WorkHistory).unwrap();
+            let starts: Vec<u32> = timeline.entries.iter()
+                .filter_map(|details| details.tenure.as_ref().map(|tenure| tenure.start_date))
+                .collect();
+            assert_eq!(starts, vec![20200101, 20210101, 20210601, 20220301, 20230301, 20240101]);
+            assert_eq!(timeline.gaps, vec![
+                TimelineGap { after: 20211231, before: 20220301 },
+                TimelineGap { after: 20220331, before: 20230301 },
+            ]);
+            assert_eq!(contract.get_timeline(alice(), ClaimType::Expertise).err(), 
+                Some(Error::InvalidClaimType));
+        }
+
+        #[ink::test]
+        fn endorsers_past_the_first_twenty_can_revoke() {
+            set_caller(alice());
+            let mut contract = ContractStorage::new();
+            contract.make_claim(ClaimType::Expertise, b"rust".to_vec(), Vec::new(), None).unwrap();
+            let claim_id = contract.compute_claim_id(alice(), ClaimType::Expertise, 
+                b"rust".to_vec()).unwrap();
+            for number in 10..35u8 {
+                set_caller(AccountId::from([number; 32]));
+                contract.endorse_claim(claim_id).unwrap();
+            }
+
+            // the first endorser is no longer in the endorsers list but still counts
+            set_caller(AccountId::from([10u8; 32]));
+            assert_eq!(contract.endorse_claim(claim_id), Err(Error::DuplicateEndorsement));
+            contract.revoke_endorsement(claim_id).unwrap();
+            assert_eq!(contract.claim_details.get(claim_id).unwrap().endorser_count, 24);
+            assert_eq!(contract.revoke_endorsement(claim_id), Err(Error::NotEndorser));
+            assert_eq!(contract.endorse_claim(claim_id), Err(Error::RevokedEndorsement));
+            contract.reendorse_claim(claim_id).unwrap();
+            assert_eq!(contract.claim_details.get(claim_id).unwrap().endorser_count, 25);
+
+            // amending without keeping the endorsements starts them over
+            set_caller(alice());
+            contract.amend_claim(claim_id, b"rust, ink".to_vec(), Vec::new(), false).unwrap();
+            set_caller(AccountId::from([11u8; 32]));
+            assert_eq!(contract.revoke_endorsement(claim_id), Err(Error::NotEndorser));
+            contract.endorse_claim(claim_id).unwrap();
+            assert_eq!(contract.claim_details.get(claim_id).unwrap().endorser_count, 1);
+        }
+
+        #[ink::test]
+        fn oldest_first_cursor_continues_after_retraction() {
+            let mut contract = ContractStorage::new();
+            make_skills(&mut contract, 0, 5);
+
+            let (claims, next_index) = skill_page(&contract, SortOrder::OldestFirst, 0, 2);
+            assert_eq!(claims, vec!["skill 0", "skill 1"]);
+            assert_eq!(next_index, Some(2));
+
+            // retracting a claim leaves an empty slot, so the cursor still points at skill 2
+            let retracted = contract.compute_claim_id(alice(), ClaimType::Expertise, 
+                b"skill 2".to_vec()).unwrap();
+            contract.retract_claim(retracted).unwrap();
+            let (claims, next_index) = skill_page(&contract, SortOrder::OldestFirst, 2, 2);
+            assert_eq!(claims, vec!["skill 3", "skill 4"]);
+            assert_eq!(next_index, None);
+        }
+
+        #[ink::test]
+        fn newest_first_cursor_ignores_new_claims() {
+            let mut contract = ContractStorage::new();
+            make_skills(&mut contract, 0, 5);
+
+            let (claims, next_index) = skill_page(&contract, SortOrder::NewestFirst, 0, 2);
+            assert_eq!(claims, vec!["skill 4", "skill 3"]);
+            assert_eq!(next_index, Some(3));
+
+            // a claim made between pages is not repeated or skipped into the next page
+            make_skills(&mut contract, 5, 1);
+            let (claims, next_index) = skill_page(&contract, SortOrder::NewestFirst, 3, 2);
+            assert_eq!(claims, vec!["skill 2", "skill 1"]);
+            assert_eq!(next_index, Some(1));
+            let (claims, next_index) = skill_page(&contract, SortOrder::NewestFirst, 1, 2);
+            assert_eq!(claims, vec!["skill 0"]);
+            assert_eq!(next_index, None);
+        }
+
+        #[ink::test]
+        fn amending_ip_content_clears_coauthor_confirmations() {
+            let (alice, bob) = (accounts().alice, accounts().bob);
+            set_caller(alice);
+            let mut contract = ContractStorage::new();
+            let claim_id = make_ip(&mut contract, "paper", 1);
+            contract.set_coauthors(claim_id, vec![(bob, 30)]).unwrap();
+            set_caller(bob);
+            contract.confirm_coauthorship(claim_id).unwrap();
+            let details = contract.claim_details.get(claim_id).unwrap();
+            assert_eq!(details.verification_level(), VerificationLevel::ConfirmedByCounterparty);
+            assert_eq!(contract.index_claims(IndexKey::CoAuthored(bob)), vec![claim_id]);
+
+            // changing only the link keeps the confirmations
+            set_caller(alice);
+            let metadata = details.metadata.clone();
+            contract.amend_claim_metadata(claim_id, metadata, b"https://paper".to_vec(), true).unwrap();
+            assert!(contract.claim_details.get(claim_id).unwrap().coauthors_confirmed);
+
+            // changing the work itself does not
+            let metadata = ClaimMetadata::IntellectualPropertyV1(IpMeta {
+                title: b"something completely different".to_vec(),
+                keywords: Vec::new(),
+                file_hash: Hash::from([2; 32]),
+            });
+            contract.amend_claim_metadata(claim_id, metadata, Vec::new(), true).unwrap();
+            let details = contract.claim_details.get(claim_id).unwrap();
+            assert!(!details.coauthors_confirmed);
+            assert!(details.coauthors.iter().all(|coauthor| !coauthor.confirmed));
+            assert_eq!(details.verification_level(), VerificationLevel::SelfAsserted);
+            assert!(contract.index_claims(IndexKey::CoAuthored(bob)).is_empty());
+
+            // until the co-author confirms the new version
+            set_caller(bob);
+            contract.confirm_coauthorship(claim_id).unwrap();
+            let details = contract.claim_details.get(claim_id).unwrap();
+            assert_eq!(details.verification_level(), VerificationLevel::ConfirmedByCounterparty);
+        }
+
+        #[ink::test]
+        fn every_keyword_of_a_claim_is_indexed() {
+            set_caller(alice());
+            let mut contract = ContractStorage::new();
+            let words = |count: usize| (0..count).map(|number| format!("w{number}"))
+                .collect::<Vec<String>>().join(" ").into_bytes();
+            contract.make_claim(ClaimType::Expertise, words(MAX_INDEXED_KEYWORDS), Vec::new(), None).unwrap();
+            let last = format!("w{}", MAX_INDEXED_KEYWORDS - 1).into_bytes();
+            let page = contract.search_by_keyword(ClaimType::Expertise, last, 
+                SearchOptions::default(), 0, 10).unwrap();
+            assert_eq!(page.results.len(), 1);
+
+            // a claim with more keywords than the index holds is rejected, made or amended
+            assert_eq!(contract.make_claim(ClaimType::Education, words(MAX_INDEXED_KEYWORDS + 1), 
+                Vec::new(), None), Err(Error::DataTooLarge));
+            let claim_id = page.results[0].claim_id;
+            assert_eq!(contract.amend_claim(claim_id, words(MAX_INDEXED_KEYWORDS + 1), Vec::new(), 
+                true), Err(Error::DataTooLarge));
+        }
+
+        #[ink::test]
+        fn most_endorsed_finds_the_most_endorsed_in_the_whole_scan() {
+            let mut contract = ContractStorage::new();
+            make_skills(&mut contract, 0, 30);
+            for (number, endorsers) in [(29u32, 3u8), (12, 1), (20, 2)] {
+                let claim_id = contract.compute_claim_id(alice(), ClaimType::Expertise, 
+                    format!("skill {number}").into_bytes()).unwrap();
+                for endorser in 0..endorsers {
+                    set_caller(AccountId::from([endorser.saturating_add(10); 32]));
+                    contract.endorse_claim(claim_id).unwrap();
+                }
+            }
+
+            let (claims, next_index) = skill_page(&contract, SortOrder::MostEndorsed, 0, 5);
+            assert_eq!(claims, vec!["skill 29", "skill 20", "skill 12", "skill 0", "skill 1"]);
+            assert_eq!(next_index, None);
+
+            // across types too, and the scan limit still applies
+            contract.make_claim(ClaimType::Education, b"skill school".to_vec(), Vec::new(), None).unwrap();
+            let options = SearchOptions { mode: MatchMode::Normalized, sort: SortOrder::MostEndorsed, 
+                ..Default::default() };
+            let page = contract.query_claims_all_types(vec![ClaimType::Education, ClaimType::Expertise], 
+                b"skill".to_vec(), options.clone(), None, 500, 2).unwrap();
+            let claims: Vec<Vec<u8>> = page.results.iter().map(|details| details.claim.clone()).collect();
+            assert_eq!(claims, vec![b"skill 29".to_vec(), b"skill 20".to_vec()]);
+            assert_eq!(page.next, None);
+            let page = contract.query_claims_all_types(vec![ClaimType::Expertise], 
+                b"skill".to_vec(), options, None, 20, 2).unwrap();
+            assert_eq!(page.results[0].claim, b"skill 12".to_vec());
+            assert_eq!(page.next, Some(SearchCursor { claim_type: ClaimType::Expertise, index: 20 }));
+        }
+
+        #[ink::test]
+        fn make_claim_makes_ip_claims_with_a_file_hash() {
+            set_caller(alice());
+            let mut contract = ContractStorage::new();
+            let file_hash = Hash::from([7; 32]);
+            assert_eq!(contract.make_claim(ClaimType::IntellectualProperty, b"paper".to_vec(), 
+                Vec::new(), None), Err(Error::MissingFileHash));
+            assert_eq!(contract.make_claim(ClaimType::Expertise, b"paper".to_vec(), 
+                Vec::new(), Some(file_hash)), Err(Error::InvalidClaimType));
+            contract.make_claim(ClaimType::IntellectualProperty, b"paper".to_vec(), Vec::new(), 
+                Some(file_hash)).unwrap();
+            let claim_id = contract.compute_claim_id(alice(), ClaimType::IntellectualProperty, 
+                b"paper".to_vec()).unwrap();
+            assert_eq!(contract.get_ip_claim_by_file_hash(file_hash).unwrap().claim_id, claim_id);
+            assert_eq!(contract.get_full_details(claim_id).file_hash, Some(file_hash));
+
+            // the legacy wrapper works out the claim_id the same way, not from the file hash
+            let file_hash = Hash::from([8; 32]);
+            contract.make_claim_intellectualproperty(b"book".to_vec(), Vec::new(), file_hash).unwrap();
+            let claim_id = contract.compute_claim_id(alice(), ClaimType::IntellectualProperty, 
+                b"book".to_vec()).unwrap();
+            assert_eq!(contract.get_ip_claim_by_file_hash(file_hash).unwrap().claim_id, claim_id);
+            assert_eq!(contract.get_full_details(file_hash).claimtype, ClaimType::Unknown);
+        }
+
+        #[ink::test]
+        fn license_terms_belong_to_the_ip_owner() {
+            let accounts = accounts();
+            set_caller(accounts.alice);
+            let mut contract = funded_contract();
+            let claim_id = make_ip(&mut contract, "paper", 1);
+            let terms = LicenseTerms { license_id: b"CC-BY".to_vec(), price: 100, transferable: true };
+            assert_eq!(buy_license(&mut contract, accounts.django, claim_id, 100), Err(Error::NotLicensable));
+
+            set_caller(accounts.bob);
+            assert_eq!(contract.set_license_terms(claim_id, Some(terms.clone())), Err(Error::CallerNotOwner));
+            contract.make_claim(ClaimType::Expertise, b"rust".to_vec(), Vec::new(), None).unwrap();
+            let expertise_id = contract.compute_claim_id(accounts.bob, ClaimType::Expertise, 
+                b"rust".to_vec()).unwrap();
+            assert_eq!(contract.set_license_terms(expertise_id, Some(terms.clone())), 
+                Err(Error::InvalidClaimType));
+
+            // licenses stop being sold when the terms are taken away
+            set_caller(accounts.alice);
+            contract.set_license_terms(claim_id, Some(terms)).unwrap();
+            contract.set_license_terms(claim_id, None).unwrap();
+            assert_eq!(buy_license(&mut contract, accounts.django, claim_id, 100), Err(Error::NotLicensable));
+        }
+
+        #[ink::test]
+        fn license_payment_is_split_between_confirmed_coauthors() {
+            let accounts = accounts();
+            set_caller(accounts.alice);
+            let mut contract = funded_contract();
+            let claim_id = make_ip(&mut contract, "paper", 1);
+            contract.set_coauthors(claim_id, vec![(accounts.bob, 30), (accounts.charlie, 33)]).unwrap();
+            set_caller(accounts.bob);
+            contract.confirm_coauthorship(claim_id).unwrap();
+            set_caller(accounts.alice);
+            contract.set_license_terms(claim_id, Some(LicenseTerms { 
+                license_id: b"MIT".to_vec(), price: 100, transferable: false })).unwrap();
+
+            assert_eq!(buy_license(&mut contract, accounts.django, claim_id, 99), 
+                Err(Error::InsufficientPayment));
+
+            // bob gets 30% of 101 rounded down, charlie has not confirmed, and alice gets the rest
+            let before = (balance(accounts.alice), balance(accounts.bob), balance(accounts.charlie));
+            buy_license(&mut contract, accounts.django, claim_id, 101).unwrap();
+            assert_eq!(balance(accounts.alice), before.0 + 71);
+            assert_eq!(balance(accounts.bob), before.1 + 30);
+            assert_eq!(balance(accounts.charlie), before.2);
+            let licenses = contract.get_licenses(accounts.django);
+            assert_eq!(licenses.len(), 1);
+            assert_eq!(licenses[0].price_paid, 101);
+
+            // a license is only sold once to each account, and never to the owner
+            assert_eq!(buy_license(&mut contract, accounts.django, claim_id, 100), 
+                Err(Error::LicenseUnavailable));
+            assert_eq!(buy_license(&mut contract, accounts.alice, claim_id, 100), 
+                Err(Error::LicenseUnavailable));
+
+            // and this one cannot be passed on
+            set_caller(accounts.django);
+            assert_eq!(contract.transfer_license(claim_id, accounts.eve), Err(Error::LicenseUnavailable));
+            assert_eq!(contract.get_licenses(accounts.eve).len(), 0);
+        }
+
+        #[ink::test]
+        fn hidden_claims_cannot_be_licensed_and_transferable_licenses_move() {
+            let accounts = accounts();
+            set_caller(accounts.alice);
+            let mut contract = funded_contract();
+            let claim_id = make_ip(&mut contract, "paper", 1);
+            contract.set_license_terms(claim_id, Some(LicenseTerms { 
+                license_id: b"CC-BY".to_vec(), price: 10, transferable: true })).unwrap();
+            contract.show_or_hide_claim(claim_id, false).unwrap();
+            assert_eq!(buy_license(&mut contract, accounts.django, claim_id, 10), 
+                Err(Error::NonexistentClaim));
+
+            set_caller(accounts.alice);
+            contract.show_or_hide_claim(claim_id, true).unwrap();
+            buy_license(&mut contract, accounts.django, claim_id, 10).unwrap();
+            assert_eq!(contract.transfer_license(claim_id, accounts.django), Err(Error::LicenseUnavailable));
+            contract.transfer_license(claim_id, accounts.eve).unwrap();
+            assert!(contract.get_licenses(accounts.django).is_empty());
+            let licenses = contract.get_licenses(accounts.eve);
+            assert_eq!(licenses.len(), 1);
+            assert_eq!(licenses[0].licensee, accounts.eve);
+            set_caller(accounts.django);
+            assert_eq!(contract.transfer_license(claim_id, accounts.frank), Err(Error::NonexistentClaim));
+        }
+
+        #[ink::test]
+        fn ip_transfers_are_offered_cancelled_and_accepted() {
+            let accounts = accounts();
+            set_caller(accounts.alice);
+            let mut contract = ContractStorage::new();
+            let claim_id = make_ip(&mut contract, "paper", 1);
+            assert_eq!(contract.offer_ip_transfer(claim_id, accounts.alice), Err(Error::PermissionDenied));
+            contract.make_claim(ClaimType::Expertise, b"rust".to_vec(), Vec::new(), None).unwrap();
+            let expertise_id = contract.compute_claim_id(accounts.alice, ClaimType::Expertise, 
+                b"rust".to_vec()).unwrap();
+            assert_eq!(contract.offer_ip_transfer(expertise_id, accounts.bob), Err(Error::InvalidClaimType));
+            set_caller(accounts.bob);
+            assert_eq!(contract.offer_ip_transfer(claim_id, accounts.bob), Err(Error::CallerNotOwner));
+            assert_eq!(contract.accept_ip_transfer(claim_id), Err(Error::PermissionDenied));
+
+            // a cancelled offer cannot be accepted
+            set_caller(accounts.alice);
+            contract.offer_ip_transfer(claim_id, accounts.bob).unwrap();
+            assert_eq!(contract.get_ip_transfer_offer(claim_id), Some(accounts.bob));
+            contract.cancel_ip_transfer(claim_id).unwrap();
+            assert_eq!(contract.get_ip_transfer_offer(claim_id), None);
+            set_caller(accounts.bob);
+            assert_eq!(contract.accept_ip_transfer(claim_id), Err(Error::PermissionDenied));
+
+            // and a new offer replaces the earlier one
+            set_caller(accounts.alice);
+            contract.offer_ip_transfer(claim_id, accounts.bob).unwrap();
+            contract.offer_ip_transfer(claim_id, accounts.charlie).unwrap();
+            set_caller(accounts.bob);
+            assert_eq!(contract.accept_ip_transfer(claim_id), Err(Error::PermissionDenied));
+            set_caller(accounts.charlie);
+            contract.accept_ip_transfer(claim_id).unwrap();
+            assert_eq!(contract.get_ip_transfer_offer(claim_id), None);
+
+            // the claim moves between the resumes and the old owner joins the provenance chain
+            let details = contract.claim_details.get(claim_id).unwrap();
+            assert_eq!(details.claimant, accounts.charlie);
+            assert_eq!(details.previous_owners, vec![accounts.alice]);
+            assert!(contract.get_resume(accounts.alice).iter().all(|item| item.claim_id != claim_id));
+            assert!(contract.get_resume(accounts.charlie).iter().any(|item| item.claim_id == claim_id));
+            assert_eq!(contract.get_ip_claim_by_file_hash(Hash::from([1; 32])).unwrap().claimant, 
+                accounts.charlie);
+
+            // the new owner can sell it on, and the chain keeps growing
+            contract.offer_ip_transfer(claim_id, accounts.django).unwrap();
+            set_caller(accounts.django);
+            contract.accept_ip_transfer(claim_id).unwrap();
+            assert_eq!(contract.claim_details.get(claim_id).unwrap().previous_owners, 
+                vec![accounts.alice, accounts.charlie]);
+        }
+
+        #[ink::test]
+        fn ip_transfers_move_the_owners_place_among_the_endorsers() {
+            let accounts = accounts();
+            set_caller(accounts.alice);
+            let mut contract = ContractStorage::new();
+            let claim_id = make_ip(&mut contract, "paper", 1);
+            set_caller(accounts.bob);
+            contract.endorse_claim(claim_id).unwrap();
+            set_caller(accounts.charlie);
+            contract.endorse_claim(claim_id).unwrap();
+
+            // bob stops being an endorser when the claim becomes theirs
+            set_caller(accounts.alice);
+            contract.offer_ip_transfer(claim_id, accounts.bob).unwrap();
+            set_caller(accounts.bob);
+            contract.accept_ip_transfer(claim_id).unwrap();
+            let details = contract.claim_details.get(claim_id).unwrap();
+            assert_eq!(details.endorsers, vec![accounts.bob, accounts.charlie]);
+            assert_eq!(details.endorser_count, 1);
+            assert_eq!(contract.endorse_claim(claim_id), Err(Error::DuplicateEndorsement));
+
+            // and alice can endorse the claim they sold only once, like anyone else
+            set_caller(accounts.alice);
+            contract.endorse_claim(claim_id).unwrap();
+            assert_eq!(contract.endorse_claim(claim_id), Err(Error::DuplicateEndorsement));
+            let details = contract.claim_details.get(claim_id).unwrap();
+            assert_eq!(details.endorsers, vec![accounts.bob, accounts.charlie, accounts.alice]);
+            assert_eq!(details.endorser_count, 2);
+        }
+
+        #[ink::test]
+        fn issued_claims_are_accepted_or_rejected_by_the_subject() {
+            let accounts = accounts();
+            set_caller(accounts.bob);
+            let mut contract = ContractStorage::new();
+            let tenure = Tenure { organization: b"Wave".to_vec(), role_or_degree: b"CTO".to_vec(), 
+                start_date: 20200101, end_date: None };
+            assert_eq!(contract.issue_claim(accounts.bob, ClaimType::WorkHistory, b"CTO".to_vec(), 
+                Vec::new(), None), Err(Error::PermissionDenied));
+            let accepted = contract.issue_claim(accounts.alice, ClaimType::WorkHistory, b"CTO".to_vec(), 
+                Vec::new(), Some(tenure)).unwrap();
+            let rejected = contract.issue_claim(accounts.alice, ClaimType::Expertise, b"rust".to_vec(), 
+                Vec::new(), None).unwrap();
+            assert_eq!(contract.get_pending_issued_claims(accounts.alice, 0).len(), 2);
+
+            // only the subject can answer
+            set_caller(accounts.charlie);
+            assert_eq!(contract.accept_issued_claim(accepted), Err(Error::CallerNotOwner));
+            set_caller(accounts.alice);
+            contract.accept_issued_claim(accepted).unwrap();
+            contract.reject_issued_claim(rejected).unwrap();
+            assert!(contract.get_pending_issued_claims(accounts.alice, 0).is_empty());
+            assert_eq!(contract.accept_issued_claim(rejected), Err(Error::NonexistentClaim));
+
+            // the accepted claim is in the subject's resume with the issuer set
+            let details = contract.claim_details.get(accepted).unwrap();
+            assert_eq!(details.claimant, accounts.alice);
+            assert_eq!(details.issuer, Some(accounts.bob));
+            assert_eq!(details.verification_level(), VerificationLevel::ConfirmedByCounterparty);
+            assert!(contract.get_resume(accounts.alice).iter().any(|item| item.claim_id == accepted));
+
+            // and the rejected claim cannot be issued again
+            set_caller(accounts.bob);
+            assert_eq!(contract.issue_claim(accounts.alice, ClaimType::Expertise, b"rust".to_vec(), 
+                Vec::new(), None), Err(Error::RejectedClaim));
+        }
+
+        #[ink::test]
+        fn issued_claims_keep_what_the_issuer_wrote() {
+            let accounts = accounts();
+            set_caller(accounts.bob);
+            let mut contract = ContractStorage::new();
+            let claim_id = contract.issue_claim(accounts.alice, ClaimType::Education, b"PhD".to_vec(), 
+                Vec::new(), None).unwrap();
+            set_caller(accounts.alice);
+            contract.accept_issued_claim(claim_id).unwrap();
+
+            assert_eq!(contract.amend_claim(claim_id, b"PhD cum laude".to_vec(), Vec::new(), true), 
+                Err(Error::PermissionDenied));
+            contract.amend_claim(claim_id, b"PhD".to_vec(), b"https://thesis".to_vec(), true).unwrap();
+            let tenure = Tenure { organization: b"UF".to_vec(), role_or_degree: b"PhD".to_vec(), 
+                start_date: 20100101, end_date: Some(20140101) };
+            assert_eq!(contract.set_claim_tenure(claim_id, Some(tenure)), Err(Error::PermissionDenied));
+
+            // issued and self-asserted claims can be told apart in a search
+            contract.make_claim(ClaimType::Education, b"PhD course".to_vec(), Vec::new(), None).unwrap();
+            let search = |issued: Option<bool>| {
+                let options = SearchOptions { mode: MatchMode::Normalized, issued, ..Default::default() };
+                contract.query_claims(ClaimType::Education, b"phd".to_vec(), options, 0, 10, 10).unwrap()
+                    .results.iter().map(|details| details.claim.clone()).collect::<Vec<Vec<u8>>>()
+            };
+            assert_eq!(search(Some(true)), vec![b"PhD".to_vec()]);
+            assert_eq!(search(Some(false)), vec![b"PhD course".to_vec()]);
+            assert_eq!(search(None).len(), 2);
+        }
+
+        #[ink::test]
+        fn pending_issued_claims_are_limited() {
+            let accounts = accounts();
+            set_caller(accounts.bob);
+            let mut contract = ContractStorage::new();
+            for number in 0..MAX_PENDING_ISSUED {
+                contract.issue_claim(accounts.alice, ClaimType::Expertise, format!("skill {number}").into_bytes(), 
+                    Vec::new(), None).unwrap();
+            }
+            assert_eq!(contract.issue_claim(accounts.alice, ClaimType::Expertise, b"one more".to_vec(), 
+                Vec::new(), None), Err(Error::DataTooLarge));
+            assert_eq!(contract.get_pending_issued_claims(accounts.alice, 0).len(), MAX_SEARCH_RESULTS as usize);
+            assert_eq!(contract.get_pending_issued_claims(accounts.alice, MAX_SEARCH_RESULTS).len(), 25);
+
+            // answering one makes room for another
+            let first = contract.get_pending_issued_claims(accounts.alice, 0)[0].claim_id;
+            set_caller(accounts.alice);
+            contract.reject_issued_claim(first).unwrap();
+            set_caller(accounts.bob);
+            contract.issue_claim(accounts.alice, ClaimType::Expertise, b"one more".to_vec(), 
+                Vec::new(), None).unwrap();
+        }
+
+        #[ink::test]
+        fn retracted_claims_leave_every_list() {
+            let accounts = accounts();
+            set_caller(accounts.alice);
+            let mut contract = funded_contract();
+            let ip_id = make_ip(&mut contract, "paper", 1);
+            contract.set_coauthors(ip_id, vec![(accounts.bob, 50)]).unwrap();
+            contract.set_license_terms(ip_id, Some(LicenseTerms { 
+                license_id: b"MIT".to_vec(), price: 10, transferable: false })).unwrap();
+            set_caller(accounts.bob);
+            contract.confirm_coauthorship(ip_id).unwrap();
+            buy_license(&mut contract, accounts.django, ip_id, 10).unwrap();
+
+            set_caller(accounts.alice);
+            contract.make_claim(ClaimType::GoodDeed, b"food bank".to_vec(), Vec::new(), None).unwrap();
+            let deed_id = contract.compute_claim_id(accounts.alice, ClaimType::GoodDeed, 
+                b"food bank".to_vec()).unwrap();
+            contract.set_beneficiary(deed_id, Some(accounts.charlie)).unwrap();
+            assert_eq!(contract.index_claims(IndexKey::Beneficiary(accounts.charlie)), vec![deed_id]);
+            assert_eq!(contract.index_claims(IndexKey::Volunteer(accounts.alice)), vec![deed_id]);
+            contract.amend_claim(deed_id, b"food bank shifts".to_vec(), Vec::new(), true).unwrap();
+
+            set_caller(accounts.bob);
+            assert_eq!(contract.retract_claim(ip_id), Err(Error::CallerNotOwner));
+            set_caller(accounts.alice);
+            contract.retract_claim(ip_id).unwrap();
+            contract.retract_claim(deed_id).unwrap();
+            assert_eq!(contract.retract_claim(ip_id), Err(Error::NonexistentClaim));
+
+            // gone from the resumes, the keyword index and the good deed lists
+            assert!(contract.get_resume(accounts.alice).is_empty());
+            assert!(contract.get_resume(accounts.bob).is_empty());
+            assert!(contract.index_claims(IndexKey::CoAuthored(accounts.bob)).is_empty());
+            assert!(contract.index_claims(IndexKey::Beneficiary(accounts.charlie)).is_empty());
+            assert!(contract.index_claims(IndexKey::Volunteer(accounts.alice)).is_empty());
+            for (claim_type, keyword) in [(ClaimType::IntellectualProperty, b"paper".to_vec()), 
+                (ClaimType::GoodDeed, b"shifts".to_vec())] {
+                let page = contract.search_by_keyword(claim_type, keyword, SearchOptions::default(), 
+                    0, 10).unwrap();
+                assert!(page.results.is_empty());
+            }
+            assert!(contract.claim_revisions.get((deed_id, 0)).is_none());
+            assert_eq!(contract.get_full_details(deed_id).claimtype, ClaimType::Unknown);
+
+            // the file hash is free for anyone to register again
+            assert!(contract.get_ip_claim_by_file_hash(Hash::from([1; 32])).is_err());
+            set_caller(accounts.eve);
+            make_ip(&mut contract, "paper", 1);
+
+            // but the same claim cannot be made again
+            set_caller(accounts.alice);
+            assert_eq!(contract.make_claim(ClaimType::GoodDeed, b"food bank".to_vec(), Vec::new(), None), 
+                Err(Error::RetractedClaim));
+
+            // and the license that was sold is kept
+            assert_eq!(contract.get_licenses(accounts.django).len(), 1);
+        }
+    }
 
 }